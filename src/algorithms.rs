@@ -0,0 +1,196 @@
+use crate::{doubly, single};
+
+/// The read-only surface shared by the list types, for algorithms generic over both.
+///
+/// The provided methods are the fallback strategies; each list overrides those it
+/// can do better with its own links.
+pub trait List<T> {
+    /// The iterator returned by [`List::iter`].
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns the number of elements in the list.
+    fn len(&self) -> usize;
+
+    /// Returns an iterator over references to the elements, front to back.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Checks if the list is empty.
+    fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Returns the element at index `len() / 2`, found by walking to it.
+    fn middle(&self) -> Option<&T> {
+        let half = self.len() / 2;
+        self.iter().nth(half)
+    }
+
+    /// Checks if the list reads the same in both directions, by collecting its
+    /// elements and comparing the collection with its reverse.
+    fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let elems: Vec<&T> = self.iter().collect();
+        elems.iter().eq(elems.iter().rev())
+    }
+
+    /// Merges the sorted list `other` into the sorted list `self`.
+    fn merge_sorted(self, other: Self) -> Self
+    where
+        Self: Sized,
+        T: Ord;
+}
+
+impl<T> List<T> for single::LinkedList<T> {
+    type Iter<'a>
+        = single::Iter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        single::LinkedList::len(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        single::LinkedList::iter(self)
+    }
+
+    /// Finds the middle with a slow and a fast pointer, without using the length.
+    fn middle(&self) -> Option<&T> {
+        let mut slow = self.iter();
+        let mut middle = slow.next()?;
+        let mut fast = self.iter();
+        while fast.next().is_some() && fast.next().is_some() {
+            middle = slow.next()?;
+        }
+        Some(middle)
+    }
+
+    fn merge_sorted(mut self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        single::LinkedList::merge_sorted(&mut self, other);
+        self
+    }
+}
+
+impl<T, const TRACK_LEN: bool> List<T> for doubly::LinkedList<T, TRACK_LEN> {
+    type Iter<'a>
+        = doubly::Iter<'a, T, TRACK_LEN>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        doubly::LinkedList::len(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        doubly::LinkedList::iter(self)
+    }
+
+    /// Compares the elements pairwise from both ends until the ends meet.
+    fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut iter = self.iter();
+        while let (Some(front), Some(back)) = (iter.next(), iter.next_back()) {
+            if front != back {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_sorted(mut self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        doubly::LinkedList::merge_sorted(&mut self, other);
+        self
+    }
+}
+
+/// Checks if `list` reads the same front to back and back to front.
+///
+/// The doubly list compares from both ends at once; the singly list has to
+/// collect its elements first.
+pub fn is_palindrome<T: PartialEq>(list: &impl List<T>) -> bool {
+    list.is_palindrome()
+}
+
+/// Returns the element at index `len() / 2`, or `None` if `list` is empty.
+///
+/// For an even length this is the second of the two middle elements.
+pub fn middle<T, L: List<T>>(list: &L) -> Option<&T> {
+    list.middle()
+}
+
+/// Merges two sorted lists of the same type into one sorted list.
+///
+/// The merge is stable, with the elements of `a` before equal elements of `b`,
+/// and relinks the existing nodes.
+pub fn merge_sorted<T: Ord, L: List<T>>(a: L, b: L) -> L {
+    a.merge_sorted(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Inputs shared by the tests, covering empty, odd and even lengths.
+    const CASES: [&[i32]; 7] = [&[], &[1], &[1, 2], &[1, 2, 1], &[1, 2, 2, 1], &[1, 2, 3, 4], &[1, 2, 3, 2, 9]];
+
+    fn singly_of(elems: &[i32]) -> single::LinkedList<i32> {
+        elems.iter().copied().collect()
+    }
+
+    fn doubly_of<const TRACK_LEN: bool>(elems: &[i32]) -> doubly::LinkedList<i32, TRACK_LEN> {
+        let mut list = doubly::LinkedList::<i32, TRACK_LEN>::default();
+        for &elem in elems {
+            list.push_back(elem);
+        }
+        list
+    }
+
+    #[test]
+    fn test_is_palindrome() {
+        for elems in CASES {
+            let expected = elems.iter().eq(elems.iter().rev());
+            assert_eq!(is_palindrome(&singly_of(elems)), expected, "{:?}", elems);
+            assert_eq!(is_palindrome(&doubly_of::<true>(elems)), expected, "{:?}", elems);
+            assert_eq!(is_palindrome(&doubly_of::<false>(elems)), expected, "{:?}", elems);
+        }
+    }
+
+    #[test]
+    fn test_middle() {
+        for elems in CASES {
+            let expected = elems.get(elems.len() / 2);
+            assert_eq!(middle(&singly_of(elems)), expected, "{:?}", elems);
+            assert_eq!(middle(&doubly_of::<true>(elems)), expected, "{:?}", elems);
+            assert_eq!(middle(&doubly_of::<false>(elems)), expected, "{:?}", elems);
+        }
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let pairs: [(&[i32], &[i32]); 4] = [(&[], &[]), (&[1, 4], &[]), (&[], &[2]), (&[1, 3, 5], &[2, 3, 6, 7])];
+        for (a, b) in pairs {
+            let mut expected = [a, b].concat();
+            expected.sort();
+            let merged = merge_sorted(singly_of(a), singly_of(b));
+            assert_eq!(merged.iter().copied().collect::<Vec<_>>(), expected);
+            let merged = merge_sorted(doubly_of::<true>(a), doubly_of(b));
+            assert_eq!(merged.iter().copied().collect::<Vec<_>>(), expected);
+            assert_eq!(merged.validate(), Ok(()));
+            let merged = merge_sorted(doubly_of::<false>(a), doubly_of(b));
+            assert_eq!(merged.iter().copied().collect::<Vec<_>>(), expected);
+        }
+    }
+}
@@ -0,0 +1,409 @@
+//! A fixed-capacity doubly linked list that never allocates.
+//!
+//! Only `core` is used here, so the list works where neither `std` nor `alloc` is
+//! available, such as in an interrupt handler.
+
+use core::fmt;
+
+/// A handle to an element of an [`ArrayLinkedList`].
+///
+/// A token names a slot and the generation the slot had when the element was
+/// inserted. Removing the element bumps the generation, so a token of a removed
+/// element is stale even after its slot has been reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Token {
+    index: usize,
+    generation: u32,
+}
+
+impl Token {
+    /// Returns the index of the slot the token refers to.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Errors returned by the inserting methods of [`ArrayLinkedList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertError {
+    /// Every slot is occupied.
+    Full,
+    /// The token does not refer to an element of the list.
+    StaleToken,
+}
+
+impl fmt::Display for InsertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InsertError::Full => write!(f, "the list is full"),
+            InsertError::StaleToken => write!(f, "the token does not refer to an element"),
+        }
+    }
+}
+
+impl core::error::Error for InsertError {}
+
+/// The state of one slot of the arena.
+enum SlotState<T> {
+    /// The slot is free; `next_free` links it into the free list.
+    Vacant { next_free: Option<usize> },
+    /// The slot holds an element linked to its neighbours by index.
+    Occupied { value: T, prev: Option<usize>, next: Option<usize> },
+}
+
+/// A slot of the arena, with the generation used to detect stale tokens.
+struct Slot<T> {
+    generation: u32,
+    state: SlotState<T>,
+}
+
+/// A doubly linked list of at most `N` elements, stored inline in an array.
+///
+/// Elements live in a `[Slot<T>; N]` arena and are linked by slot index, so
+/// removing an element from the middle through its [`Token`] takes O(1) time.
+/// Freed slots are kept in a free list and reused. Inserting into a full list
+/// fails with [`InsertError::Full`] and hands the element back.
+pub struct ArrayLinkedList<T, const N: usize> {
+    slots: [Slot<T>; N],
+    head: Option<usize>,
+    tail: Option<usize>,
+    /// The most recently freed slot, heading the free list.
+    free: Option<usize>,
+    /// Slots at or after this index have never been used.
+    untouched: usize,
+    length: usize,
+}
+
+impl<T, const N: usize> ArrayLinkedList<T, N> {
+    /// Creates an empty list.
+    ///
+    /// This is a `const fn`, so a list can be built in a `static` or `const` item.
+    pub const fn new() -> Self {
+        ArrayLinkedList {
+            slots: [const { Slot { generation: 0, state: SlotState::Vacant { next_free: None } } }; N],
+            head: None,
+            tail: None,
+            free: None,
+            untouched: 0,
+            length: 0,
+        }
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Checks if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Checks if every slot is occupied, so the next insertion would fail.
+    pub fn is_full(&self) -> bool {
+        self.length == N
+    }
+
+    /// Returns the maximum number of elements, `N`.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Adds an element to the front of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The element to add.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsertError::Full`] together with `value` if the list is full.
+    pub fn push_front(&mut self, value: T) -> Result<Token, (InsertError, T)> {
+        let head = self.head;
+        self.link(value, None, head)
+    }
+
+    /// Adds an element to the back of the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The element to add.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsertError::Full`] together with `value` if the list is full.
+    pub fn push_back(&mut self, value: T) -> Result<Token, (InsertError, T)> {
+        let tail = self.tail;
+        self.link(value, tail, None)
+    }
+
+    /// Inserts an element just after the one `token` refers to.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - A token of an element of this list.
+    /// * `value` - The element to insert.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error together with `value` if the token is stale or the list is full.
+    pub fn insert_after(&mut self, token: Token, value: T) -> Result<Token, (InsertError, T)> {
+        match self.links(token) {
+            Some((_, next)) => self.link(value, Some(token.index), next),
+            None => Err((InsertError::StaleToken, value)),
+        }
+    }
+
+    /// Removes and returns the first element, if any.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.unlink(self.head?)
+    }
+
+    /// Removes and returns the last element, if any.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.unlink(self.tail?)
+    }
+
+    /// Removes the element `token` refers to, making the token stale.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - A token of an element of this list.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or `None` if the token is stale.
+    pub fn remove(&mut self, token: Token) -> Option<T> {
+        self.links(token)?;
+        self.unlink(token.index)
+    }
+
+    /// Returns a reference to the element `token` refers to, or `None` if it is stale.
+    pub fn get(&self, token: Token) -> Option<&T> {
+        match self.slots.get(token.index)? {
+            Slot { generation, state: SlotState::Occupied { value, .. } } if *generation == token.generation => {
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over references to the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter { list: self, next: self.head, remaining: self.length }
+    }
+
+    /// Returns the `prev` and `next` links of the element `token` refers to.
+    fn links(&self, token: Token) -> Option<(Option<usize>, Option<usize>)> {
+        match self.slots.get(token.index)? {
+            Slot { generation, state: SlotState::Occupied { prev, next, .. } } if *generation == token.generation => {
+                Some((*prev, *next))
+            }
+            _ => None,
+        }
+    }
+
+    /// Sets the `prev` link of the element in slot `index`, or the tail if `index` is `None`.
+    fn set_prev(&mut self, index: Option<usize>, link: Option<usize>) {
+        match index {
+            Some(index) => match &mut self.slots[index].state {
+                SlotState::Occupied { prev, .. } => *prev = link,
+                SlotState::Vacant { .. } => unreachable!("linked slot is vacant"),
+            },
+            None => self.tail = link,
+        }
+    }
+
+    /// Sets the `next` link of the element in slot `index`, or the head if `index` is `None`.
+    fn set_next(&mut self, index: Option<usize>, link: Option<usize>) {
+        match index {
+            Some(index) => match &mut self.slots[index].state {
+                SlotState::Occupied { next, .. } => *next = link,
+                SlotState::Vacant { .. } => unreachable!("linked slot is vacant"),
+            },
+            None => self.head = link,
+        }
+    }
+
+    /// Stores `value` in a free slot linked between `prev` and `next`.
+    fn link(&mut self, value: T, prev: Option<usize>, next: Option<usize>) -> Result<Token, (InsertError, T)> {
+        let index = match self.free {
+            Some(index) => {
+                if let SlotState::Vacant { next_free } = self.slots[index].state {
+                    self.free = next_free;
+                }
+                index
+            }
+            None if self.untouched < N => {
+                self.untouched += 1;
+                self.untouched - 1
+            }
+            None => return Err((InsertError::Full, value)),
+        };
+
+        self.slots[index].state = SlotState::Occupied { value, prev, next };
+        self.set_next(prev, Some(index));
+        self.set_prev(next, Some(index));
+        self.length += 1;
+        Ok(Token { index, generation: self.slots[index].generation })
+    }
+
+    /// Takes the element out of slot `index`, returning the slot to the free list.
+    fn unlink(&mut self, index: usize) -> Option<T> {
+        let slot = &mut self.slots[index];
+        let vacant = SlotState::Vacant { next_free: self.free };
+        let SlotState::Occupied { value, prev, next } = core::mem::replace(&mut slot.state, vacant) else {
+            unreachable!("linked slot is vacant");
+        };
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free = Some(index);
+
+        self.set_next(prev, next);
+        self.set_prev(next, prev);
+        self.length -= 1;
+        Some(value)
+    }
+}
+
+impl<T, const N: usize> Default for ArrayLinkedList<T, N> {
+    fn default() -> Self {
+        ArrayLinkedList::new()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayLinkedList<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over the elements of an [`ArrayLinkedList`].
+pub struct Iter<'a, T, const N: usize> {
+    list: &'a ArrayLinkedList<T, N>,
+    next: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &self.list.slots[self.next?].state {
+            SlotState::Occupied { value, next, .. } => {
+                self.next = *next;
+                self.remaining -= 1;
+                Some(value)
+            }
+            SlotState::Vacant { .. } => unreachable!("linked slot is vacant"),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Iter<'_, T, N> {}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayLinkedList<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contents<const N: usize>(list: &ArrayLinkedList<i32, N>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_push_pop_both_ends() {
+        let mut list = ArrayLinkedList::<i32, 4>::new();
+        assert!(list.is_empty());
+        list.push_back(2).unwrap();
+        list.push_front(1).unwrap();
+        list.push_back(3).unwrap();
+        assert_eq!(contents(&list), [1, 2, 3]);
+        assert_eq!(list.len(), 3);
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_full_list_rejects_inserts() {
+        let mut list = ArrayLinkedList::<String, 2>::new();
+        let first = list.push_back("a".to_string()).unwrap();
+        list.push_back("b".to_string()).unwrap();
+        assert!(list.is_full());
+
+        assert_eq!(list.push_front("c".to_string()), Err((InsertError::Full, "c".to_string())));
+        assert_eq!(list.insert_after(first, "d".to_string()), Err((InsertError::Full, "d".to_string())));
+        assert_eq!(InsertError::Full.to_string(), "the list is full");
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn test_fill_drain_cycles_reuse_slots() {
+        let mut list = ArrayLinkedList::<i32, 3>::new();
+        for round in 0..10 {
+            let tokens: Vec<Token> = (0..3).map(|i| list.push_back(round * 10 + i).unwrap()).collect();
+            assert!(tokens.iter().all(|token| token.index() < 3));
+            assert!(list.push_back(-1).is_err());
+            assert_eq!(contents(&list), [round * 10, round * 10 + 1, round * 10 + 2]);
+
+            while list.pop_front().is_some() {}
+            assert!(tokens.iter().all(|&token| list.get(token).is_none()));
+        }
+    }
+
+    #[test]
+    fn test_tokens_remove_from_the_middle() {
+        let mut list = ArrayLinkedList::<i32, 5>::new();
+        let one = list.push_back(1).unwrap();
+        let three = list.push_back(3).unwrap();
+        let two = list.insert_after(one, 2).unwrap();
+        list.insert_after(three, 4).unwrap();
+        assert_eq!(contents(&list), [1, 2, 3, 4]);
+        assert_eq!(list.get(two), Some(&2));
+
+        assert_eq!(list.remove(two), Some(2));
+        assert_eq!(list.remove(two), None);
+        assert_eq!(list.insert_after(two, 9), Err((InsertError::StaleToken, 9)));
+        assert_eq!(contents(&list), [1, 3, 4]);
+
+        // The freed slot is reused, but the old token stays stale.
+        let five = list.push_front(5).unwrap();
+        assert_eq!(five.index(), two.index());
+        assert_eq!(list.get(two), None);
+        assert_eq!(list.get(five), Some(&5));
+
+        assert_eq!(list.remove(one), Some(1));
+        assert_eq!(list.remove(five), Some(5));
+        assert_eq!(contents(&list), [3, 4]);
+        assert_eq!(list.iter().len(), 2);
+        assert_eq!(format!("{:?}", list), "[3, 4]");
+    }
+
+    #[test]
+    fn test_usable_without_allocation() {
+        // A static is built without calling an allocator. That the module compiles
+        // with `core` alone is checked by the `no_std` crate in `tests/no-std`.
+        static STATIC: ArrayLinkedList<u8, 8> = ArrayLinkedList::new();
+        assert!(STATIC.is_empty());
+
+        let mut list = ArrayLinkedList::<u8, 8>::default();
+        list.push_back(7).unwrap();
+        assert_eq!(list.iter().copied().sum::<u8>(), 7);
+        assert_eq!(list.capacity(), 8);
+    }
+}
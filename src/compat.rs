@@ -0,0 +1,382 @@
+use crate::doubly;
+
+/// A doubly linked list with the method surface of `std::collections::LinkedList`.
+///
+/// Code written against the standard list compiles against this type by changing
+/// only the `use` line. It wraps a [`doubly::LinkedList`] and never hands out node
+/// handles, entries or observers, which is what lets it return plain `&mut T`
+/// where the doubly list returns `RefMut`.
+pub struct LinkedList<T> {
+    inner: doubly::LinkedList<T>,
+}
+
+/// An iterator over references to the elements of a [`LinkedList`].
+pub type Iter<'a, T> = doubly::Iter<'a, T>;
+
+/// An owning iterator over the elements of a [`LinkedList`].
+pub type IntoIter<T> = doubly::IntoIter<T>;
+
+impl<T> LinkedList<T> {
+    /// Constructs a new, empty `LinkedList`.
+    pub fn new() -> Self {
+        LinkedList { inner: doubly::LinkedList::new() }
+    }
+
+    /// Moves every element of `other` to the end of the list in O(1), leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        self.inner.append(&mut other.inner);
+    }
+
+    /// Returns an iterator over references to the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.inner.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { inner: self.inner.iter_mut() }
+    }
+
+    /// Checks if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Removes every element.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Checks if the list contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.inner.contains(x)
+    }
+
+    /// Returns a reference to the first element, if any.
+    pub fn front(&self) -> Option<&T> {
+        self.inner.front()
+    }
+
+    /// Returns a mutable reference to the first element, if any.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.inner.front_mut().map(unguard)
+    }
+
+    /// Returns a reference to the last element, if any.
+    pub fn back(&self) -> Option<&T> {
+        self.inner.back()
+    }
+
+    /// Returns a mutable reference to the last element, if any.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.inner.back_mut().map(unguard)
+    }
+
+    /// Inserts an element at the front of the list.
+    pub fn push_front(&mut self, elt: T) {
+        self.inner.push_front(elt);
+    }
+
+    /// Removes the first element and returns it, or `None` if the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.inner.pop_front()
+    }
+
+    /// Appends an element to the back of the list.
+    pub fn push_back(&mut self, elt: T) {
+        self.inner.push_back(elt);
+    }
+
+    /// Removes the last element and returns it, or `None` if the list is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.inner.pop_back()
+    }
+
+    /// Splits the list in two at `at`, returning the elements from that position on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len()`.
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        LinkedList { inner: self.inner.split_off(at) }
+    }
+}
+
+/// Turns the borrow of an element of a compat list into a plain reference.
+///
+/// The guard only matters while someone else could borrow the same element, and
+/// nothing outside the compat list can reach its nodes.
+fn unguard<T>(mut elem: std::cell::RefMut<'_, T>) -> &mut T {
+    let ptr: *mut T = &mut *elem;
+    drop(elem);
+    // SAFETY: the `RefMut` proves that the list was borrowed mutably for its whole
+    // lifetime, and a compat list gives out no handles, entries or observers, so
+    // the element cannot be borrowed again, moved or freed while the reference lives.
+    unsafe { &mut *ptr }
+}
+
+/// An iterator over mutable references to the elements of a [`LinkedList`].
+pub struct IterMut<'a, T> {
+    inner: doubly::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(unguard)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(unguard)
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        LinkedList { inner: self.inner.clone() }
+    }
+}
+
+/// Implementation of the Debug trait, printing the elements like a slice as the
+/// standard list does.
+impl<T: std::fmt::Debug> std::fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.inner.partial_cmp(&other.inner)
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<T: std::hash::Hash> std::hash::Hash for LinkedList<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        LinkedList { inner: iter.into_iter().collect() }
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Tests written against `std::collections::LinkedList`, expanded once for the
+    /// standard list and once for the compat list; only the `use` line differs.
+    macro_rules! std_list_tests {
+        () => {
+            use std::collections::hash_map::DefaultHasher;
+            use std::hash::{Hash, Hasher};
+
+            fn hash_of<T: Hash>(value: &T) -> u64 {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            }
+
+            #[test]
+            fn test_push_pop() {
+                let mut list = LinkedList::new();
+                assert!(list.is_empty());
+                list.push_back(2);
+                list.push_front(1);
+                list.push_back(3);
+                assert_eq!(list.len(), 3);
+                assert_eq!(list.front(), Some(&1));
+                assert_eq!(list.back(), Some(&3));
+                assert_eq!(list.pop_front(), Some(1));
+                assert_eq!(list.pop_back(), Some(3));
+                assert_eq!(list.pop_back(), Some(2));
+                assert_eq!(list.pop_front(), None);
+                assert_eq!(list.front(), None);
+            }
+
+            #[test]
+            fn test_front_back_mut() {
+                let mut list: LinkedList<i32> = (1..=3).collect();
+                *list.front_mut().unwrap() += 10;
+                if let Some(back) = list.back_mut() {
+                    *back *= 2;
+                }
+                assert_eq!(list.front_mut(), Some(&mut 11));
+                assert_eq!(list.iter().copied().collect::<Vec<_>>(), [11, 2, 6]);
+
+                let mut empty = LinkedList::<i32>::new();
+                assert_eq!(empty.front_mut(), None);
+                assert_eq!(empty.back_mut(), None);
+            }
+
+            #[test]
+            fn test_iterators() {
+                let mut list: LinkedList<i32> = (1..=4).collect();
+                for elem in list.iter_mut() {
+                    *elem *= 10;
+                }
+                for elem in &mut list {
+                    *elem += 1;
+                }
+                let refs: Vec<&mut i32> = list.iter_mut().rev().collect();
+                assert_eq!(refs.len(), 4);
+                assert_eq!(*refs[0], 41);
+
+                assert_eq!(list.iter().len(), 4);
+                assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [41, 31, 21, 11]);
+                let mut sum = 0;
+                for elem in &list {
+                    sum += elem;
+                }
+                assert_eq!(sum, 104);
+                let mut owned = list.into_iter();
+                assert_eq!(owned.next_back(), Some(41));
+                assert_eq!(owned.collect::<Vec<_>>(), [11, 21, 31]);
+            }
+
+            #[test]
+            fn test_append_split_off() {
+                let mut list: LinkedList<i32> = (1..=3).collect();
+                let mut other: LinkedList<i32> = (4..=6).collect();
+                list.append(&mut other);
+                assert!(other.is_empty());
+                assert_eq!(list.len(), 6);
+
+                let tail = list.split_off(4);
+                assert_eq!(tail.iter().copied().collect::<Vec<_>>(), [5, 6]);
+                assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+                assert!(list.split_off(4).is_empty());
+                let all = list.split_off(0);
+                assert!(list.is_empty());
+                assert_eq!(all.len(), 4);
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_split_off_out_of_range() {
+                let mut list: LinkedList<i32> = (1..=3).collect();
+                list.split_off(4);
+            }
+
+            #[test]
+            fn test_contains_clear() {
+                let mut list: LinkedList<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+                assert!(list.contains(&"b".to_string()));
+                assert!(!list.contains(&"c".to_string()));
+                list.clear();
+                assert!(list.is_empty());
+                assert!(!list.contains(&"a".to_string()));
+            }
+
+            #[test]
+            fn test_traits() {
+                let list: LinkedList<i32> = (1..=3).collect();
+                let copy = list.clone();
+                assert_eq!(list, copy);
+                assert_eq!(hash_of(&list), hash_of(&copy));
+                assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+
+                let mut longer = copy.clone();
+                longer.extend([4, 5]);
+                longer.extend(&[6]);
+                assert!(list < longer);
+                assert_eq!(list.cmp(&longer), std::cmp::Ordering::Less);
+                assert_ne!(list, longer);
+                assert_eq!(longer.len(), 6);
+
+                let empty: LinkedList<i32> = Default::default();
+                assert_eq!(empty, LinkedList::new());
+                assert_eq!(format!("{:?}", empty), "[]");
+            }
+        };
+    }
+
+    mod std_list {
+        use std::collections::LinkedList;
+
+        std_list_tests!();
+    }
+
+    mod compat_list {
+        use crate::compat::LinkedList;
+
+        std_list_tests!();
+    }
+}
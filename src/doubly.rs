@@ -1,12 +1,29 @@
-use std::cell::RefCell;
+use std::borrow::Borrow;
+#[cfg(debug_assertions)]
+use std::cell::Cell;
+use std::cell::{Ref, RefCell, RefMut};
+use std::ops::Range;
+use std::ptr;
 use std::rc::{Rc, Weak};
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::stats::Counters;
+use crate::ConsistencyError;
+
+/// Source of unique list identities used by the debug-mode ownership checks.
+#[cfg(debug_assertions)]
+static NEXT_LIST_ID: AtomicUsize = AtomicUsize::new(1);
 
 /// A node in a doubly-linked list, containing data of generic type `T`.
 #[derive(Clone)]
 pub struct Node<T> {
-    data: T,
+    data: RefCell<T>,
     prev: RefCell<Option<Weak<Node<T>>>>,
     next: RefCell<Option<Rc<Node<T>>>>,
+    /// Identity of the list currently holding this node, or 0 when detached.
+    #[cfg(debug_assertions)]
+    owner: Cell<usize>,
 }
 
 impl<T> Node<T> {
@@ -17,219 +34,4662 @@ impl<T> Node<T> {
     /// * `data` - The data to store in the new `Node`.
     fn new(data: T) -> Rc<Self> {
         Rc::new(Node {
-            data,
+            data: RefCell::new(data),
             prev: RefCell::new(None),
             next: RefCell::new(None),
+            #[cfg(debug_assertions)]
+            owner: Cell::new(0),
         })
     }
 
     /// Returns a reference to the node's data.
-    pub fn data(&self) -> &T {
-        &self.data
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data is currently borrowed mutably.
+    pub fn data(&self) -> Ref<'_, T> {
+        self.data.borrow()
+    }
+
+    /// Checks whether the node's data is equal to `value`, in any borrowed form.
+    fn data_eq<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        Borrow::<Q>::borrow(&*self.data.borrow()) == value
+    }
+}
+
+/// Errors returned by the non-panicking removal methods of [`LinkedList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopError {
+    /// The list contains no elements.
+    Empty,
+    /// The node is still referenced from outside the list, so its data cannot be moved out.
+    Shared,
+}
+
+impl std::fmt::Display for PopError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PopError::Empty => write!(f, "the list is empty"),
+            PopError::Shared => write!(f, "the node has external strong references"),
+        }
+    }
+}
+
+impl std::error::Error for PopError {}
+
+/// The result of [`LinkedList::pop_front_shared`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PopOutcome<T> {
+    /// The list held the only strong reference, so the element was moved out.
+    Unique(T),
+    /// The node was unlinked, but another strong reference keeps it alive.
+    ///
+    /// The element is dropped once the last outside reference goes away; until
+    /// then it still occupies memory without being part of any list.
+    SharedDropped,
+}
+
+/// The repairs made by [`LinkedList::fsck`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FsckReport {
+    /// Number of `prev` links that were rewritten.
+    pub prev_fixed: usize,
+    /// Number of `next` links that were rewritten: a link looping back to an earlier
+    /// node was cut, or the tail of a ring was linked back to the head.
+    pub next_fixed: usize,
+    /// Change applied to the recorded length; always 0 for an uncounted list.
+    pub length_delta: isize,
+    /// Whether the tail pointer had to be moved to the last node.
+    pub tail_fixed: bool,
+}
+
+impl FsckReport {
+    /// Returns `true` if the list needed no repairs.
+    pub fn is_clean(&self) -> bool {
+        *self == FsckReport::default()
+    }
+}
+
+/// A change reported to the observer of a list; see [`LinkedList::set_observer`].
+///
+/// `T` is a reference to the affected element, which in the popped and removed
+/// cases has already left the list.
+#[cfg(feature = "observer")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListEvent<T> {
+    /// An element was inserted at the front.
+    PushedFront(T),
+    /// An element was inserted at the back.
+    PushedBack(T),
+    /// The front element was removed.
+    PoppedFront(T),
+    /// The back element was removed.
+    PoppedBack(T),
+    /// The element at `index` was removed from the middle of the list.
+    Removed {
+        /// The position the element had before it was removed.
+        index: usize,
+        /// The removed element.
+        elem: T,
+    },
+    /// Every element was removed.
+    Cleared,
+}
+
+#[cfg(feature = "observer")]
+impl<T: Clone> ListEvent<&T> {
+    /// Clones the affected element, so the event can outlive the list borrow.
+    pub fn cloned(self) -> ListEvent<T> {
+        match self {
+            ListEvent::PushedFront(elem) => ListEvent::PushedFront(elem.clone()),
+            ListEvent::PushedBack(elem) => ListEvent::PushedBack(elem.clone()),
+            ListEvent::PoppedFront(elem) => ListEvent::PoppedFront(elem.clone()),
+            ListEvent::PoppedBack(elem) => ListEvent::PoppedBack(elem.clone()),
+            ListEvent::Removed { index, elem } => ListEvent::Removed { index, elem: elem.clone() },
+            ListEvent::Cleared => ListEvent::Cleared,
+        }
+    }
+}
+
+/// The callback registered with [`LinkedList::set_observer`].
+#[cfg(feature = "observer")]
+type Observer<T> = Box<dyn FnMut(ListEvent<&T>)>;
+
+/// A weak reference to a node of a [`LinkedList`].
+///
+/// A handle stays valid while its element is in the list and becomes stale once
+/// the element is removed. In debug builds, passing a handle to a list that does
+/// not own its node panics instead of silently corrupting both lists.
+///
+/// Structural operations relink the existing nodes instead of rebuilding them, so
+/// removing an element is the only way to invalidate its handle: after a sort,
+/// reverse, rotation or splice, a handle still refers to the same element. An
+/// element spliced into another list keeps its handle, which then belongs to that
+/// list. Every mutating method of [`LinkedList`] states which handles it invalidates.
+pub struct NodeHandle<T> {
+    node: Weak<Node<T>>,
+}
+
+impl<T> NodeHandle<T> {
+    /// Returns `true` if the handle still refers to an element.
+    pub fn is_valid(&self) -> bool {
+        self.node.strong_count() > 0
+    }
+
+    /// Returns a copy of the element the handle refers to, if it still exists.
+    pub fn get(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.node.upgrade().map(|node| node.data.borrow().clone())
+    }
+}
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        NodeHandle { node: self.node.clone() }
     }
 }
 
 /// A doubly-linked list with elements of generic type `T`.
-pub struct LinkedList<T> {
+///
+/// By default the list keeps its length up to date, so [`LinkedList::len`] is O(1).
+/// With `TRACK_LEN = false` (see [`UncountedList`]) no length is stored: `len`
+/// counts the nodes in O(n) instead, and operations that move a chain of nodes
+/// whose size is not known up front never have to walk it just to count it.
+///
+/// # Element addresses
+///
+/// Each element lives in its own reference-counted node and stays at the same
+/// address for as long as it is in the list. Reordering methods such as
+/// [`LinkedList::sort`], [`LinkedList::reverse`], [`LinkedList::rotate`] and the
+/// splices relink nodes instead of moving elements. Elements are only moved by
+/// the methods that remove them, by [`SpanEditor::replace_current`], and by
+/// whatever a caller does through the `RefMut`s handed out by entries, cursors
+/// and editors. Because of that last point the list cannot offer `Pin<&T>` to
+/// elements that are not `Unpin`; store `Pin<Box<T>>` elements instead.
+pub struct LinkedList<T, const TRACK_LEN: bool = true> {
     head: Option<Rc<Node<T>>>,
     tail: Option<Weak<Node<T>>>,
+    /// The number of elements; always 0 when `TRACK_LEN` is false.
     length: usize,
+    /// Whether the tail links back to the head; see [`LinkedList::make_circular`].
+    circular: bool,
+    /// Identity stamped on every node the list owns, for debug-mode handle checks.
+    #[cfg(debug_assertions)]
+    id: usize,
+    /// Operation counters, only recorded with the `stats` feature.
+    stats: Counters,
+    /// Callback notified of changes; see [`LinkedList::set_observer`].
+    #[cfg(feature = "observer")]
+    observer: Option<Observer<T>>,
 }
 
+/// A doubly-linked list that does not track its length.
+pub type UncountedList<T> = LinkedList<T, false>;
+
 impl<T> LinkedList<T> {
     /// Constructs a new, empty `LinkedList`.
     pub fn new() -> Self {
-        LinkedList { head: None, tail: None, length: 0 }
+        Self::empty()
+    }
+}
+
+impl<T> UncountedList<T> {
+    /// Constructs a new, empty list that does not track its length.
+    pub fn uncounted() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, const TRACK_LEN: bool> LinkedList<T, TRACK_LEN> {
+    /// Constructs an empty list in either length-tracking mode.
+    fn empty() -> Self {
+        LinkedList {
+            head: None,
+            tail: None,
+            length: 0,
+            circular: false,
+            #[cfg(debug_assertions)]
+            id: NEXT_LIST_ID.fetch_add(1, Ordering::Relaxed),
+            stats: Counters::default(),
+            #[cfg(feature = "observer")]
+            observer: None,
+        }
     }
 
     /// Inserts an element at the front of the list.
     ///
+    /// Every handle stays valid.
+    ///
     /// # Arguments
     ///
     /// * `elem` - The data to insert at the front of the list.
     pub fn push_front(&mut self, elem: T) {
-        let new_node = Node::new(elem);
-        self.length += 1;
-        match self.head.take() {
-            Some(old_head) => {
-                old_head.prev.borrow_mut().replace(Rc::downgrade(&new_node));
-                new_node.next.borrow_mut().replace(old_head);
-                self.head = Some(new_node);
-            }
-            None => {
-                let weak_new_node = Rc::downgrade(&new_node);
-                self.head = Some(new_node);
-                self.tail = Some(weak_new_node);
+        self.attach_front(Node::new(elem), "push_front");
+        self.stats.allocated(1);
+        #[cfg(feature = "observer")]
+        self.notify(|list, observer| {
+            if let Some(elem) = list.iter().next() {
+                observer(ListEvent::PushedFront(elem));
             }
-        }
+        });
     }
 
     /// Removes and returns the element at the front of the list, if any.
+    ///
+    /// Only the handle of the removed element is invalidated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the front node is still referenced from outside the list.
+    /// Use [`LinkedList::try_pop_front`] to handle that case without panicking.
     pub fn pop_front(&mut self) -> Option<T> {
-        let res = self.head.take().and_then(|head_node| {
-            match head_node.next.borrow_mut().take() {
-                Some(next_node) => {
-                    *next_node.prev.borrow_mut() = None;
-                    self.head = Some(next_node);
-                }
-                None => {
-                    self.tail = None;
-                }
-            }
-
-            Some(Rc::try_unwrap(head_node).ok().unwrap().data)
-        });
-
-        if res.is_some() {
-            self.length = self.length.saturating_sub(1); // Decrement length safely
+        match self.try_pop_front() {
+            Ok(elem) => Some(elem),
+            Err(PopError::Empty) => None,
+            Err(err) => panic!("pop_front failed: {}", err),
         }
+    }
 
-        res
+    /// Removes and returns the element at the front of the list.
+    ///
+    /// Unlike [`LinkedList::pop_front`], this never panics: if the front node is
+    /// still referenced from outside the list, the list is left untouched and
+    /// `Err(PopError::Shared)` is returned.
+    ///
+    /// Only the handle of the removed element is invalidated.
+    ///
+    /// # Errors
+    ///
+    /// * `PopError::Empty` - The list contains no elements.
+    /// * `PopError::Shared` - The front node has external strong references.
+    pub fn try_pop_front(&mut self) -> Result<T, PopError> {
+        let head_node = self.head.clone().ok_or(PopError::Empty)?;
+        let elem = self.try_unlink(head_node)?;
+        #[cfg(feature = "observer")]
+        self.notify(|_, observer| observer(ListEvent::PoppedFront(&elem)));
+        Ok(elem)
     }
 
     /// Inserts an element at the back of the list.
     ///
+    /// Every handle stays valid.
+    ///
     /// # Arguments
     ///
     /// * `elem` - The data to insert at the back of the list.
     pub fn push_back(&mut self, elem: T) {
-        let new_node = Node::new(elem);
-        self.length += 1;
-        match self.tail.replace(Rc::downgrade(&new_node)) {
-            Some(old_tail_weak) => {
-                if let Some(old_tail) = old_tail_weak.upgrade() {
-                    *old_tail.next.borrow_mut() = Some(new_node.clone());
-                    *new_node.prev.borrow_mut() = Some(old_tail_weak);
-                }
-            }
-            None => {
-                self.head = Some(new_node.clone());
+        self.attach_back(Node::new(elem), "push_back");
+        self.stats.allocated(1);
+        #[cfg(feature = "observer")]
+        self.notify(|list, observer| {
+            if let Some(elem) = list.iter().next_back() {
+                observer(ListEvent::PushedBack(elem));
             }
-        }
+        });
     }
 
     /// Removes and returns the element at the back of the list, if any.
+    ///
+    /// Only the handle of the removed element is invalidated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the back node is still referenced from outside the list.
+    /// Use [`LinkedList::try_pop_back`] to handle that case without panicking.
     pub fn pop_back(&mut self) -> Option<T> {
-        if self.tail.is_some() {
-            self.length = self.length.saturating_sub(1); // Decrement length safely
+        match self.try_pop_back() {
+            Ok(elem) => Some(elem),
+            Err(PopError::Empty) => None,
+            Err(err) => panic!("pop_back failed: {}", err),
         }
+    }
 
-        let tail_weak = self.tail.take();
-        let old_tail = match tail_weak.and_then(|weak| weak.upgrade()) {
-            Some(node) => node,
-            None => return None,
-        };
+    /// Removes and returns the element at the back of the list.
+    ///
+    /// Unlike [`LinkedList::pop_back`], this never panics: if the back node is
+    /// still referenced from outside the list, the list is left untouched and
+    /// `Err(PopError::Shared)` is returned.
+    ///
+    /// Only the handle of the removed element is invalidated.
+    ///
+    /// # Errors
+    ///
+    /// * `PopError::Empty` - The list contains no elements.
+    /// * `PopError::Shared` - The back node has external strong references.
+    pub fn try_pop_back(&mut self) -> Result<T, PopError> {
+        let tail_node = self
+            .tail
+            .as_ref()
+            .and_then(|weak| weak.upgrade())
+            .ok_or(PopError::Empty)?;
+        let elem = self.try_unlink(tail_node)?;
+        #[cfg(feature = "observer")]
+        self.notify(|_, observer| observer(ListEvent::PoppedBack(&elem)));
+        Ok(elem)
+    }
 
-        let prev_node = old_tail.prev.borrow_mut().take().and_then(|weak| weak.upgrade());
-        match prev_node {
-            Some(prev) => {
-                *prev.next.borrow_mut() = None;
-                self.tail = Some(Rc::downgrade(&prev));
-            }
-            None => {
-                // If there's no previous node, it means the list had only one element.
-                self.head = None;
+    /// Removes the front element even if its node is referenced from outside the list.
+    ///
+    /// Where [`LinkedList::pop_front`] panics on a shared node, this unlinks it
+    /// anyway and reports that the element could not be moved out. Only the handle
+    /// of the removed element is invalidated.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the list is empty, otherwise whether the element was extracted or
+    /// left alive behind an outside reference.
+    pub fn pop_front_shared(&mut self) -> Option<PopOutcome<T>> {
+        match self.try_pop_front() {
+            Ok(elem) => Some(PopOutcome::Unique(elem)),
+            Err(PopError::Empty) => None,
+            Err(PopError::Shared) => {
+                let head_node = self.head.clone()?;
+                self.detach(&head_node);
+                #[cfg(feature = "observer")]
+                self.notify(|_, observer| observer(ListEvent::PoppedFront(&head_node.data.borrow())));
+                Some(PopOutcome::SharedDropped)
             }
         }
+    }
 
-        let res = Rc::try_unwrap(old_tail).ok().map(|node| node.data);
-        if res.is_some() {
-            self.length = self.length.saturating_sub(1); // Decrement length safely
-        }
+    /// Counts the nodes that are referenced from outside the list.
+    ///
+    /// Such nodes cannot be popped without [`LinkedList::pop_front_shared`], and
+    /// removing them leaves their element alive, which makes this useful when looking
+    /// for leaked elements. Takes O(n) time.
+    pub fn shared_node_count(&self) -> usize {
+        // Each node is held by its link and by the walk's own clone; in ring mode the
+        // head is also held by the tail.
+        let head = self.head.as_ref().map(Rc::as_ptr);
+        self.nodes()
+            .filter(|node| {
+                let own = 2 + usize::from(self.circular && head == Some(Rc::as_ptr(node)));
+                Rc::strong_count(node) > own
+            })
+            .count()
+    }
 
-        res
+    /// Returns the number of strong references to the front node, if any.
+    ///
+    /// A count greater than one means the node is referenced from outside the
+    /// list and cannot currently be popped.
+    pub fn strong_count_front(&self) -> Option<usize> {
+        self.head.as_ref().map(|head| Rc::strong_count(head) - usize::from(self.circular))
     }
 
-    /// Returns the number of elements in the list.
-    pub fn len(&self) -> usize {
-        self.length
+    /// Returns the number of strong references to the back node, if any.
+    ///
+    /// A count greater than one means the node is referenced from outside the
+    /// list and cannot currently be popped.
+    pub fn strong_count_back(&self) -> Option<usize> {
+        let tail = self.tail.as_ref()?;
+        let is_head = self.head.as_ref().is_some_and(|head| ptr::eq(Rc::as_ptr(head), tail.as_ptr()));
+        Some(tail.strong_count() - usize::from(self.circular && is_head))
     }
-    
-    /// Clears the list, removing all elements.
-    pub fn clear(&mut self) {
-        *self = Self::new();
+
+    /// Checks if the list contains an element equal to `value`.
+    ///
+    /// The value may be any borrowed form of the element type, so a
+    /// `LinkedList<String>` can be searched with a `&str`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to search for.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.nodes().any(|node| node.data_eq(value))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Counts the elements equal to `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to count, in any borrowed form of the element type.
+    pub fn count_of<Q>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.nodes().filter(|node| node.data_eq(value)).count()
+    }
 
-    #[test]
-    fn test_push_and_pop_front() {
-        let mut list = LinkedList::new();
-        assert_eq!(list.pop_front(), None);
+    /// Returns the index of the first occurrence of `seq` as a contiguous run of elements.
+    ///
+    /// An empty sequence is found at index 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `seq` - The values to search for, in any borrowed form of the element type.
+    pub fn find_seq<'q, Q, I>(&self, seq: I) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized + 'q,
+        I: IntoIterator<Item = &'q Q>,
+        I::IntoIter: Clone,
+    {
+        let seq = seq.into_iter();
+        if seq.clone().next().is_none() {
+            return Some(0);
+        }
 
-        list.push_front(1);
-        list.push_front(2);
-        list.push_front(3);
+        self.nodes().position(|start| {
+            let mut current = Some(start);
+            seq.clone().all(|value| match current.take() {
+                Some(node) if node.data_eq(value) => {
+                    current = node.next.borrow().clone();
+                    true
+                }
+                _ => false,
+            })
+        })
+    }
 
-        assert_eq!(list.pop_front(), Some(3));
-        assert_eq!(list.pop_front(), Some(2));
-        assert_eq!(list.pop_front(), Some(1));
-        assert_eq!(list.pop_front(), None);
+    /// Removes and returns the first element equal to `value`, if any.
+    ///
+    /// Only the handle of the removed element is invalidated.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to remove, in any borrowed form of the element type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matching node is still referenced from outside the list.
+    pub fn remove_first<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let (_index, node) = self.nodes().enumerate().find(|(_, node)| node.data_eq(value))?;
+        match self.try_unlink(node) {
+            Ok(elem) => {
+                #[cfg(feature = "observer")]
+                self.notify(|_, observer| observer(ListEvent::Removed { index: _index, elem: &elem }));
+                Some(elem)
+            }
+            Err(err) => panic!("remove_first failed: {}", err),
+        }
     }
 
-    #[test]
-    fn test_push_and_pop_back() {
-        let mut list = LinkedList::new();
-        assert_eq!(list.pop_back(), None);
+    /// Removes every element for which `keep` returns `false`, reporting each removal.
+    ///
+    /// For every removed element, in order, `on_removed` receives the element's handle,
+    /// which is stale by then, together with the element itself; callers keeping an
+    /// index of handles can use it to drop their entries. Only the handles of removed
+    /// elements are invalidated. Retained elements are never passed to `on_removed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep` - Returns `true` for the elements to keep.
+    /// * `on_removed` - Called with the handle and payload of each removed element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a node to be removed is still referenced from outside the list.
+    pub fn retain_with<F, G>(&mut self, mut keep: F, mut on_removed: G)
+    where
+        F: FnMut(&T) -> bool,
+        G: FnMut(NodeHandle<T>, T),
+    {
+        let mut budget = self.step_budget();
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            budget = budget.checked_sub(1).unwrap_or_else(|| cycle_detected());
+            current = match self.circular && self.is_tail(&node) {
+                true => None,
+                false => node.next.borrow().clone(),
+            };
+            if keep(&node.data.borrow()) {
+                continue;
+            }
 
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
+            let handle = NodeHandle { node: Rc::downgrade(&node) };
+            match self.try_unlink(node) {
+                Ok(elem) => on_removed(handle, elem),
+                Err(err) => panic!("retain_with failed: {}", err),
+            }
+        }
+    }
 
-        assert_eq!(list.pop_back(), Some(3));
-        assert_eq!(list.pop_back(), Some(2));
-        assert_eq!(list.pop_back(), Some(1));
-        assert_eq!(list.pop_back(), None);
+    /// Converts the list into a singly linked list with the same elements in the same order.
+    ///
+    /// No element is cloned. The nodes of the two list types have different layouts,
+    /// so each payload is moved into a newly allocated singly-linked node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any node is still referenced from outside the list.
+    pub fn into_singly(self) -> crate::single::LinkedList<T> {
+        let mut singly = crate::single::LinkedList::new();
+        singly.link_at_tail(self.into_iter());
+        singly
     }
 
-    #[test]
-    fn test_mixed_push_pop() {
-        let mut list = LinkedList::new();
+    /// Moves every element of `other` to the end of the list in O(1).
+    ///
+    /// Handles to elements of either list stay valid; those of `other` now belong to this list.
+    ///
+    /// The two chains are joined by relinking the boundary nodes, so no element is
+    /// moved or cloned. Debug builds additionally retag every moved node with this
+    /// list's identity, which makes the call O(n) there.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list whose elements are appended, in order.
+    pub fn extend_list(&mut self, other: LinkedList<T, TRACK_LEN>) {
+        let tail = self.tail.as_ref().and_then(|weak| weak.upgrade());
+        self.splice_between(tail.as_ref(), None, other, "extend_list");
+    }
 
-        list.push_front(1);
-        list.push_back(2);
-        list.push_front(3);
-        list.push_back(4);
+    /// Moves every element of `other` to the end of the list in O(1), leaving `other` empty.
+    ///
+    /// This is [`LinkedList::extend_list`] for a list that is borrowed rather than
+    /// owned, with the same effect on handles.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list whose elements are appended, in order.
+    pub fn append(&mut self, other: &mut LinkedList<T, TRACK_LEN>) {
+        self.extend_list(std::mem::take(other));
+    }
 
-        // Expected list: 3 -> 1 -> 2 -> 4
+    /// Removes the expired prefix of a list sorted by deadline and returns it.
+    ///
+    /// Handles stay valid; those of drained elements now belong to the returned list.
+    ///
+    /// The list is assumed to be sorted by `key` in ascending order. Elements are
+    /// removed from the front while their key is less than or equal to `now`, and the
+    /// removed prefix is cut off with a single relink, so this takes O(removed) time.
+    /// On an unsorted list, draining simply stops at the first unexpired element.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time; elements whose key equals `now` are expired.
+    /// * `key` - Extracts the deadline of an element.
+    ///
+    /// # Panics
+    ///
+    /// The prefix is only cut off after every key has been compared, so if `key`
+    /// panics the list is left unchanged.
+    pub fn drain_expired<K: Ord, F: FnMut(&T) -> K>(&mut self, now: K, mut key: F) -> LinkedList<T, TRACK_LEN> {
+        let mut last = None;
+        let mut count = 0;
+        for node in self.nodes() {
+            if key(&node.data.borrow()) > now {
+                break;
+            }
+            last = Some(node);
+            count += 1;
+        }
+        self.stats.traversed(count);
 
-        assert_eq!(list.pop_front(), Some(3));
-        assert_eq!(list.pop_back(), Some(4));
-        assert_eq!(list.pop_front(), Some(1));
-        assert_eq!(list.pop_back(), Some(2));
-        assert_eq!(list.pop_front(), None);
-        assert_eq!(list.pop_back(), None);
+        match last {
+            Some(last) => self.cut_front(&last, count),
+            None => LinkedList::empty(),
+        }
     }
 
-    #[test]
-    fn test_length_after_operations() {
-        let mut list = LinkedList::new();
+    /// Cuts the list after `last`, the node at index `count - 1`, and returns the front part.
+    fn cut_front(&mut self, last: &Rc<Node<T>>, count: usize) -> LinkedList<T, TRACK_LEN> {
+        self.open_ring();
+        let mut front = LinkedList::empty();
+        front.head = match last.next.borrow_mut().take() {
+            Some(next) => {
+                *next.prev.borrow_mut() = None;
+                self.head.replace(next)
+            }
+            None => {
+                self.tail = None;
+                self.head.take()
+            }
+        };
+        front.tail = Some(Rc::downgrade(last));
 
-        list.push_front(1);
-        list.push_back(2);
-        assert_eq!(list.len(), 2); // Ensure `len` method exists and works as expected
+        self.shrink_length(count);
+        self.close_ring();
+        front.grow_length(count, "cut_front");
+        #[cfg(debug_assertions)]
+        for node in front.nodes() {
+            node.owner.set(front.id);
+        }
+        self.stats.relinked(1);
 
-        list.pop_front();
-        assert_eq!(list.len(), 1);
+        front
+    }
 
-        list.pop_back();
-        assert_eq!(list.len(), 0);
+    /// Converts every element with a fallible closure, consuming the list.
+    ///
+    /// Conversion stops at the first error; the elements converted so far and
+    /// the elements not yet visited are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The conversion applied to each element, front to back.
+    ///
+    /// # Errors
+    ///
+    /// Returns the index of the failing element together with its error.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the element it was given, the elements converted so far and
+    /// the elements not yet visited are each dropped exactly once.
+    pub fn try_map<U, E, F>(self, mut f: F) -> Result<LinkedList<U, TRACK_LEN>, (usize, E)>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        let mut mapped = LinkedList::empty();
+        for (index, elem) in self.into_iter().enumerate() {
+            mapped.push_back(f(elem).map_err(|err| (index, err))?);
+        }
+
+        Ok(mapped)
     }
-    
-    #[test]
-    fn test_clear() {
-        let mut list = LinkedList::new();
 
-        list.push_front(1);
-        list.push_back(2);
-        list.clear();
+    /// Returns an iterator over references to the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T, TRACK_LEN> {
+        Iter {
+            list: self,
+            front: self.head.as_deref(),
+            back: self.back_node(),
+            remaining: self.step_budget(),
+        }
+    }
 
-        assert_eq!(list.len(), 0);
-        assert_eq!(list.pop_front(), None);
-        assert_eq!(list.pop_back(), None);
+    /// Returns an iterator over mutable borrows of the elements, front to back.
+    ///
+    /// Every handle stays valid.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if an element it reaches is already borrowed, e.g.
+    /// through [`NodeHandle::get`] while a previous item is still held.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, TRACK_LEN> {
+        IterMut { nodes: self.iter() }
     }
-}
 
+    /// Returns an iterator over every element together with its neighbours.
+    ///
+    /// Each item is `(prev, current, next)`, read straight from the node's links in
+    /// a single pass; the first element has no `prev` and the last no `next`, also
+    /// in ring mode.
+    pub fn iter_with_neighbors(&self) -> Neighbors<'_, T, TRACK_LEN> {
+        Neighbors { nodes: self.node_refs() }
+    }
+
+    /// Checks every structural invariant of the list.
+    ///
+    /// The `prev` link of each node must point at the node before it, the tail must
+    /// be the last node, and a counted list must link exactly `length` nodes. The
+    /// walk stops at the first broken `prev` link, so a `next` link looping back to
+    /// an earlier node is reported instead of followed forever. This is available in
+    /// every build and takes O(n) time.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first violated invariant.
+    pub fn validate(&self) -> Result<(), ConsistencyError> {
+        let mut count = 0;
+        let mut prev: Option<&Node<T>> = None;
+        let mut current = self.head.as_deref();
+        while let Some(node) = current {
+            if self.prev_node(node).map(ptr::from_ref) != prev.map(ptr::from_ref) {
+                return Err(ConsistencyError::BrokenPrevLink { index: count });
+            }
+            prev = Some(node);
+            count += 1;
+            current = self.next_node(node);
+        }
+
+        if self.back_node().map(ptr::from_ref) != prev.map(ptr::from_ref) {
+            return Err(ConsistencyError::BrokenTail);
+        }
+        if let (true, Some(head), Some(tail)) = (self.circular, self.head.as_ref(), prev) {
+            let tail_next = tail.next.borrow().as_ref().map(Rc::as_ptr);
+            let head_prev = head.prev.borrow().as_ref().map(Weak::as_ptr);
+            if tail_next != Some(Rc::as_ptr(head)) || head_prev != Some(ptr::from_ref(tail)) {
+                return Err(ConsistencyError::BrokenRing);
+            }
+        }
+        if TRACK_LEN && self.length != count {
+            return Err(ConsistencyError::LengthMismatch { expected: self.length, actual: count });
+        }
+        Ok(())
+    }
+
+    /// Checks the list's consistency; an alias of [`LinkedList::validate`] matching
+    /// [`crate::single::LinkedList::assert_consistent`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first violated invariant.
+    pub fn assert_consistent(&self) -> Result<(), ConsistencyError> {
+        self.validate()
+    }
+
+    /// Repairs the structure of a damaged list and reports what was changed.
+    ///
+    /// The chain of `next` links starting at the head is taken as the source of
+    /// truth. Every `prev` link is rebuilt from it, the tail is moved to the last
+    /// reachable node and a counted list recounts its length. A `next` link back to
+    /// a node already visited is cut (or, in ring mode, pointed at the head), so no
+    /// reachable element is ever dropped. After `fsck`, [`LinkedList::validate`]
+    /// succeeds. Handles stay valid. This takes O(n) time and O(n) extra space.
+    ///
+    /// # Returns
+    ///
+    /// The repairs that were made; [`FsckReport::is_clean`] if there were none.
+    pub fn fsck(&mut self) -> FsckReport {
+        let mut report = FsckReport::default();
+        let mut seen = std::collections::HashSet::new();
+        let mut count = 0;
+        let mut last: Option<Rc<Node<T>>> = None;
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            seen.insert(Rc::as_ptr(&node));
+            count += 1;
+
+            let linked_prev = node.prev.borrow().as_ref().map(Weak::as_ptr);
+            let expected_prev = last.as_ref().map(Rc::as_ptr);
+            if (last.is_some() || !self.circular) && linked_prev != expected_prev {
+                *node.prev.borrow_mut() = last.as_ref().map(Rc::downgrade);
+                report.prev_fixed += 1;
+            }
+
+            let next = node.next.borrow().clone();
+            current = match next {
+                Some(next) if seen.contains(&Rc::as_ptr(&next)) => {
+                    if !self.circular {
+                        *node.next.borrow_mut() = None;
+                        report.next_fixed += 1;
+                    }
+                    None
+                }
+                next => next,
+            };
+            last = Some(node);
+        }
+
+        if let (true, Some(head), Some(tail)) = (self.circular, self.head.as_ref(), last.as_ref()) {
+            if tail.next.borrow().as_ref().map(Rc::as_ptr) != Some(Rc::as_ptr(head)) {
+                *tail.next.borrow_mut() = Some(head.clone());
+                report.next_fixed += 1;
+            }
+            if head.prev.borrow().as_ref().map(Weak::as_ptr) != Some(Rc::as_ptr(tail)) {
+                *head.prev.borrow_mut() = Some(Rc::downgrade(tail));
+                report.prev_fixed += 1;
+            }
+        }
+
+        if self.tail.as_ref().map(Weak::as_ptr) != last.as_ref().map(Rc::as_ptr) {
+            self.tail = last.as_ref().map(Rc::downgrade);
+            report.tail_fixed = true;
+        }
+        if TRACK_LEN && self.length != count {
+            report.length_delta = count as isize - self.length as isize;
+            self.length = count;
+        }
+        report
+    }
+
+    /// Returns the element at `index`, or the last element if `index` is past the end.
+    ///
+    /// Unlike a strict index lookup, this only returns `None` when the list is empty.
+    /// Clamped lookups go straight to the tail, and in-range lookups walk from
+    /// whichever end of the list is closer.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The requested position.
+    pub fn get_clamped(&self, index: usize) -> Option<&T> {
+        let last = self.len().checked_sub(1)?;
+        self.node_at(index.min(last)).map(|node| self.data_of(node))
+    }
+
+    /// Returns the index of the partition point of a list partitioned by `pred`.
+    ///
+    /// As with [`slice::partition_point`], the list is assumed to hold the
+    /// elements for which `pred` returns `true` first, followed by the rest; the
+    /// result is the index of the first element of the second group. This scans
+    /// forward from the head, so it takes O(n) time, stops at the first `false`
+    /// and never allocates.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Returns `true` for the elements of the leading group.
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().take_while(|elem| pred(elem)).count()
+    }
+
+    /// Returns the same partition point as [`LinkedList::partition_point`], scanning backward.
+    ///
+    /// The scan starts at the tail and follows `prev` links until `pred` returns
+    /// `true`, which is cheaper when the partition point lies near the end. It
+    /// takes O(n) time and never allocates; uncounted lists walk once more to learn
+    /// their length.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Returns `true` for the elements of the leading group.
+    pub fn rpartition_point<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        let trailing = self.iter().rev().take_while(|elem| !pred(elem)).count();
+        self.len() - trailing
+    }
+
+    /// Copies the elements into `buf`, replacing its contents, and returns them as a slice.
+    ///
+    /// The vector's allocation is reused, so calling this repeatedly with the same
+    /// buffer only reallocates when the list outgrows its capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - The vector to refill.
+    pub fn copy_into<'a>(&self, buf: &'a mut Vec<T>) -> &'a [T]
+    where
+        T: Clone,
+    {
+        buf.clear();
+        if TRACK_LEN {
+            buf.reserve(self.length);
+        }
+        for node in self.node_refs() {
+            buf.push(self.data_of(node).clone());
+        }
+        buf
+    }
+
+    /// Copies the leading elements into `buf` without allocating.
+    ///
+    /// Returns the number of elements copied, which is the smaller of `len()` and
+    /// `buf.len()`; slots past that count are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - The slice to fill from the front.
+    pub fn copy_into_slice(&self, buf: &mut [T]) -> usize
+    where
+        T: Clone,
+    {
+        let mut copied = 0;
+        for (slot, node) in buf.iter_mut().zip(self.node_refs()) {
+            slot.clone_from(self.data_of(node));
+            copied += 1;
+        }
+
+        copied
+    }
+
+    /// Returns the node at `index`, walking from the closer end of the list.
+    fn node_at(&self, index: usize) -> Option<&Node<T>> {
+        let length = self.len();
+        if index >= length {
+            return None;
+        }
+
+        if index < length / 2 {
+            let mut node = self.head.as_deref()?;
+            for _ in 0..index {
+                node = self.next_node(node)?;
+            }
+            self.stats.traversed(index);
+            Some(node)
+        } else {
+            let mut node = self.back_node()?;
+            for _ in index + 1..length {
+                node = self.prev_node(node)?;
+            }
+            self.stats.traversed(length - 1 - index);
+            Some(node)
+        }
+    }
+
+    /// Returns the last node, borrowed for as long as the list.
+    fn back_node(&self) -> Option<&Node<T>> {
+        let tail = self.tail.as_ref().filter(|weak| weak.strong_count() > 0)?;
+        // SAFETY: the tail node is owned by the list (through its predecessor or the head),
+        // and nodes are only unlinked through `&mut self`.
+        Some(unsafe { &*tail.as_ptr() })
+    }
+
+    /// Returns the node after `node`, borrowed for as long as the list.
+    ///
+    /// Links are only rewritten through `&mut self`, so while the list is shared the
+    /// `next` link of one of its nodes stays unchanged and keeps the next node alive.
+    fn next_node<'a>(&'a self, node: &'a Node<T>) -> Option<&'a Node<T>> {
+        if self.circular && self.is_tail(node) {
+            return None;
+        }
+        // SAFETY: `next` cells are only borrowed mutably through `&mut self` (see above),
+        // and `try_borrow_unguarded` checks that no mutable borrow is active right now.
+        unsafe { node.next.try_borrow_unguarded() }.ok()?.as_deref()
+    }
+
+    /// Returns the node before `node`, borrowed for as long as the list.
+    fn prev_node<'a>(&'a self, node: &'a Node<T>) -> Option<&'a Node<T>> {
+        if self.circular && self.head.as_deref().is_some_and(|head| ptr::eq(head, node)) {
+            return None;
+        }
+        // SAFETY: as for `next_node`; the previous node is owned by the list through
+        // its own predecessor or the head.
+        let prev = unsafe { node.prev.try_borrow_unguarded() }.ok()?.as_ref()?;
+        if prev.strong_count() == 0 {
+            return None;
+        }
+        Some(unsafe { &*prev.as_ptr() })
+    }
+
+    /// Returns `true` if `node` is the last node of the list.
+    fn is_tail(&self, node: &Node<T>) -> bool {
+        self.tail.as_ref().is_some_and(|tail| ptr::eq(tail.as_ptr(), node))
+    }
+
+    /// Returns the data of `node`, borrowed for as long as the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data is currently borrowed mutably.
+    fn data_of<'a>(&'a self, node: &'a Node<T>) -> &'a T {
+        // SAFETY: element data is only borrowed mutably through `&mut self` (entries) or
+        // while the caller holds the only strong reference, so it stays unchanged while
+        // the list is shared; `try_borrow_unguarded` rejects an active mutable borrow.
+        match unsafe { node.data.try_borrow_unguarded() } {
+            Ok(data) => data,
+            Err(_) => panic!("element is currently borrowed mutably"),
+        }
+    }
+
+    /// Walks the list from front to back, yielding a strong reference to each node.
+    ///
+    /// In ring mode the walk stops at the tail instead of wrapping around.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chain is longer than the recorded length, which means the links form a cycle.
+    fn nodes(&self) -> impl Iterator<Item = Rc<Node<T>>> + '_ {
+        let mut budget = self.step_budget();
+        let next = |node: &Rc<Node<T>>| match self.circular && self.is_tail(node) {
+            true => None,
+            false => node.next.borrow().clone(),
+        };
+        std::iter::successors(self.head.clone(), next).inspect(move |_| {
+            budget = budget.checked_sub(1).unwrap_or_else(|| cycle_detected());
+        })
+    }
+
+    /// Walks the list from front to back, yielding a borrowed reference to each node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chain is longer than the recorded length, which means the links form a cycle.
+    fn node_refs(&self) -> NodeRefs<'_, T, TRACK_LEN> {
+        NodeRefs {
+            list: self,
+            next: self.head.as_deref(),
+            budget: self.step_budget(),
+        }
+    }
+
+    /// Returns how many nodes a traversal may visit before the list must be corrupted.
+    ///
+    /// An uncounted list has no length to check against, so its traversals are unbounded.
+    fn step_budget(&self) -> usize {
+        if TRACK_LEN {
+            self.length
+        } else {
+            usize::MAX
+        }
+    }
+
+    /// Unlinks `node` from the list and moves its data out.
+    ///
+    /// If the node has strong references other than the list's own link and
+    /// `node` itself, the list is left untouched and `Err(PopError::Shared)`
+    /// is returned.
+    fn try_unlink(&mut self, node: Rc<Node<T>>) -> Result<T, PopError> {
+        self.open_ring();
+        if Rc::strong_count(&node) > 2 {
+            self.close_ring();
+            return Err(PopError::Shared);
+        }
+
+        self.detach(&node);
+        self.stats.freed(1);
+        Ok(Self::unwrap_detached(node))
+    }
+
+    /// Drops elements from the front until at most `n` remain.
+    ///
+    /// Handles to the dropped elements are invalidated; the others stay valid.
+    ///
+    /// This keeps the newest `n` elements of a list used as a bounded history and
+    /// takes O(len - n) time; nodes are unlinked and dropped one at a time. Nodes
+    /// still referenced from outside the list are unlinked but not freed.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of elements to keep; `0` clears the list.
+    pub fn keep_last_n(&mut self, n: usize) {
+        let excess = match self.len().checked_sub(n) {
+            Some(excess) if excess > 0 => excess,
+            _ => return,
+        };
+
+        self.open_ring();
+        for _ in 0..excess {
+            if let Some(node) = self.head.take() {
+                self.head = node.next.borrow_mut().take();
+                #[cfg(debug_assertions)]
+                node.owner.set(0);
+            }
+        }
+        match &self.head {
+            Some(head) => *head.prev.borrow_mut() = None,
+            None => self.tail = None,
+        }
+        self.close_ring();
+        self.shrink_length(excess);
+        self.stats.freed(excess);
+    }
+
+    /// Switches the list to ring mode, linking the tail back to the head.
+    ///
+    /// Every handle stays valid.
+    ///
+    /// A ring keeps its front and back: iteration, `len` and every other method
+    /// still see the elements from head to tail, pushes and pops keep the ring
+    /// closed, and [`LinkedList::rotate`] moves the head around the ring in O(1)
+    /// per step. Dropping or clearing the list opens the ring first, so the
+    /// reference cycle never leaks. Calling this on a ring does nothing.
+    pub fn make_circular(&mut self) {
+        self.circular = true;
+        self.close_ring();
+    }
+
+    /// Switches the list back from ring mode, unlinking the tail from the head.
+    ///
+    /// Every handle stays valid.
+    ///
+    /// Calling this on a list that is not a ring does nothing.
+    pub fn break_cycle(&mut self) {
+        self.open_ring();
+        self.circular = false;
+    }
+
+    /// Returns `true` if the list is in ring mode; see [`LinkedList::make_circular`].
+    pub fn is_circular(&self) -> bool {
+        self.circular
+    }
+
+    /// Rotates the list so that the element at index `n % len()` becomes the front.
+    ///
+    /// The first `n % len()` elements move to the back, in order, like
+    /// `VecDeque::rotate_left`. The ring is closed for the duration, so each step
+    /// only moves the head and tail markers one node forward and no node is
+    /// relinked; every handle stays valid. Uncounted lists first walk once to
+    /// learn their length.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of elements to move from the front to the back.
+    pub fn rotate(&mut self, n: usize) {
+        let length = self.len();
+        if length == 0 {
+            return;
+        }
+
+        let was_circular = self.circular;
+        self.make_circular();
+        let steps = n % length;
+        for _ in 0..steps {
+            if let Some(head) = self.head.take() {
+                self.head = head.next.borrow().clone();
+                self.tail = Some(Rc::downgrade(&head));
+            }
+        }
+        if !was_circular {
+            self.break_cycle();
+        }
+        self.stats.traversed(steps);
+    }
+
+    /// Sorts the list in ascending order, keeping equal elements in their original order.
+    ///
+    /// The nodes themselves are relinked in sorted order, so every handle stays valid
+    /// and keeps referring to the same element. This takes O(n log n) time and
+    /// O(n) scratch space for the node pointers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element is borrowed mutably, e.g. through an entry held elsewhere.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list with a comparator, keeping equal elements in their original order.
+    ///
+    /// Like [`LinkedList::sort`], this relinks the nodes and keeps every handle valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - The ordering between two elements.
+    pub fn sort_by<F: FnMut(&T, &T) -> std::cmp::Ordering>(&mut self, mut compare: F) {
+        let mut nodes: Vec<Rc<Node<T>>> = self.nodes().collect();
+        nodes.sort_by(|a, b| compare(&a.data.borrow(), &b.data.borrow()));
+        self.relink_in_order(nodes);
+    }
+
+    /// Merges the sorted list `other` into this sorted list, keeping the result sorted.
+    ///
+    /// The merge is stable: equal elements keep their order, and those of this list
+    /// come before those of `other`. The nodes are relinked, so every handle stays
+    /// valid and those of `other` now belong to this list. This takes O(n + m) time
+    /// and O(n + m) scratch space for the node pointers.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A sorted list whose elements are merged in.
+    pub fn merge_sorted(&mut self, other: LinkedList<T, TRACK_LEN>)
+    where
+        T: Ord,
+    {
+        let mut left = self.nodes().collect::<Vec<_>>().into_iter().peekable();
+        let mut right = other.nodes().collect::<Vec<_>>().into_iter().peekable();
+        self.extend_list(other);
+
+        let mut merged = Vec::with_capacity(left.len() + right.len());
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            if *b.data.borrow() < *a.data.borrow() {
+                merged.extend(right.next());
+            } else {
+                merged.extend(left.next());
+            }
+        }
+        merged.extend(left);
+        merged.extend(right);
+        self.relink_in_order(merged);
+    }
+
+    /// Reverses the order of the elements in place.
+    ///
+    /// The nodes are relinked back to front, so every handle stays valid. This takes
+    /// O(n) time and O(n) scratch space for the node pointers.
+    pub fn reverse(&mut self) {
+        let mut nodes: Vec<Rc<Node<T>>> = self.nodes().collect();
+        nodes.reverse();
+        self.relink_in_order(nodes);
+    }
+
+    /// Inserts every element of `other` before the element at `index` in O(index).
+    ///
+    /// An `index` equal to `len()` appends `other`. The nodes of `other` are relinked,
+    /// not moved or cloned, so handles to elements of either list stay valid; those
+    /// of `other` now belong to this list.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position the first element of `other` ends up at.
+    /// * `other` - The list to splice in, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len()`.
+    pub fn splice_at(&mut self, index: usize, other: LinkedList<T, TRACK_LEN>) {
+        let length = self.len();
+        assert!(index <= length, "splice_at: index {} out of range for list of length {}", index, length);
+
+        self.open_ring();
+        let next = self.nodes().nth(index);
+        self.stats.traversed(index);
+        let prev = match &next {
+            Some(node) => node.prev.borrow().as_ref().and_then(|weak| weak.upgrade()),
+            None => self.tail.as_ref().and_then(|weak| weak.upgrade()),
+        };
+        self.splice_between(prev.as_ref(), next, other, "splice_at");
+        self.close_ring();
+    }
+
+    /// Moves the elements at `range` of `src` into this list, starting at position `at`.
+    ///
+    /// The sub-chain is cut out of `src` and spliced in without moving or cloning
+    /// any element, so handles to the moved elements stay valid and now belong to
+    /// this list. The cost is the walk to the cut points in `src` and to `at` in
+    /// this list; the relinking itself is O(1). The two lists cannot be the same,
+    /// since both are borrowed mutably.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - The list the elements are taken from.
+    /// * `range` - The positions of the elements in `src`.
+    /// * `at` - The position the first moved element ends up at in this list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds for `src` or `at > len()`; neither list is
+    /// changed in that case.
+    pub fn transfer_range(&mut self, src: &mut Self, range: Range<usize>, at: usize) {
+        let src_length = src.len();
+        assert!(
+            range.start <= range.end && range.end <= src_length,
+            "transfer_range: range {:?} out of range for list of length {}",
+            range,
+            src_length
+        );
+        let length = self.len();
+        assert!(at <= length, "transfer_range: index {} out of range for list of length {}", at, length);
+        if range.is_empty() {
+            return;
+        }
+
+        let moved = src.cut_range(range);
+        self.splice_at(at, moved);
+    }
+
+    /// Unlinks the nodes at `range`, which must be non-empty and in bounds, into a new list.
+    fn cut_range(&mut self, range: Range<usize>) -> LinkedList<T, TRACK_LEN> {
+        let count = range.len();
+        self.open_ring();
+        let mut walk = self.nodes().skip(range.start).take(count);
+        let (first, last) = match walk.next() {
+            Some(first) => {
+                let last = walk.last().unwrap_or_else(|| first.clone());
+                (first, last)
+            }
+            None => unreachable!("cut range is empty"),
+        };
+        self.stats.traversed(range.end);
+
+        let prev = first.prev.borrow_mut().take().and_then(|weak| weak.upgrade());
+        let next = last.next.borrow_mut().take();
+        match &next {
+            Some(next_node) => *next_node.prev.borrow_mut() = prev.as_ref().map(Rc::downgrade),
+            None => self.tail = prev.as_ref().map(Rc::downgrade),
+        }
+        match prev {
+            Some(prev_node) => *prev_node.next.borrow_mut() = next,
+            None => self.head = next,
+        }
+        self.close_ring();
+        self.shrink_length(count);
+
+        let mut cut = LinkedList::empty();
+        cut.length = if TRACK_LEN { count } else { 0 };
+        cut.tail = Some(Rc::downgrade(&last));
+        cut.head = Some(first);
+        cut
+    }
+
+    /// Applies `f` to every element in place, front to back.
+    ///
+    /// Every handle stays valid.
+    ///
+    /// Each element is visited exactly once and nothing is moved or reallocated.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The transformation applied to each element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element is borrowed while `f` runs, e.g. through
+    /// [`NodeHandle::get`] on one of this list's handles from inside `f`.
+    ///
+    /// No link is touched, so if `f` panics the list stays intact: the elements
+    /// before the panicking one keep their new values and the rest are unchanged,
+    /// apart from whatever `f` wrote into the element it was given.
+    pub fn map_in_place<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        for node in self.node_refs() {
+            f(&mut node.data.borrow_mut());
+        }
+    }
+
+    /// Threads a running state through the elements, front to back, updating both.
+    ///
+    /// Every handle stays valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial state.
+    /// * `f` - The step applied to the state and each element.
+    ///
+    /// # Returns
+    ///
+    /// The final state, or `init` if the list is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element is borrowed while `f` runs, as [`LinkedList::map_in_place`] does.
+    pub fn scan_in_place<S, F: FnMut(&mut S, &mut T)>(&mut self, init: S, mut f: F) -> S {
+        let mut state = init;
+        self.map_in_place(|elem| f(&mut state, elem));
+        state
+    }
+
+    /// Threads a running state through the elements, back to front, following the
+    /// `prev` links. Suffix sums are the mirror image of the prefix sums of
+    /// [`LinkedList::scan_in_place`].
+    ///
+    /// Every handle stays valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial state.
+    /// * `f` - The step applied to the state and each element.
+    ///
+    /// # Returns
+    ///
+    /// The final state, or `init` if the list is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element is borrowed while `f` runs, as [`LinkedList::map_in_place`] does.
+    pub fn rscan_in_place<S, F: FnMut(&mut S, &mut T)>(&mut self, init: S, mut f: F) -> S {
+        let mut state = init;
+        let mut current = self.back_node();
+        while let Some(node) = current {
+            f(&mut state, &mut node.data.borrow_mut());
+            current = self.prev_node(node);
+        }
+        state
+    }
+
+    /// Returns a reference to the first element, if any.
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_deref().map(|node| self.data_of(node))
+    }
+
+    /// Returns a reference to the last element, if any.
+    pub fn back(&self) -> Option<&T> {
+        self.back_node().map(|node| self.data_of(node))
+    }
+
+    /// Returns a mutable borrow of the first element, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is already borrowed.
+    pub fn front_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.head.as_deref().map(|node| node.data.borrow_mut())
+    }
+
+    /// Returns a mutable borrow of the last element, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is already borrowed.
+    pub fn back_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.back_node().map(|node| node.data.borrow_mut())
+    }
+
+    /// Provides an entry for the front of the list, for in-place inspection and modification.
+    ///
+    /// Returns `Entry::Occupied` if the list has a first element, `Entry::Vacant` otherwise.
+    pub fn front_entry(&mut self) -> Entry<'_, T, TRACK_LEN> {
+        match self.head.clone() {
+            Some(node) => Entry::Occupied(OccupiedEntry { list: self, node }),
+            None => Entry::Vacant(VacantEntry { list: self, front: true }),
+        }
+    }
+
+    /// Provides an entry for the back of the list, for in-place inspection and modification.
+    ///
+    /// Returns `Entry::Occupied` if the list has a last element, `Entry::Vacant` otherwise.
+    pub fn back_entry(&mut self) -> Entry<'_, T, TRACK_LEN> {
+        match self.tail.as_ref().and_then(|weak| weak.upgrade()) {
+            Some(node) => Entry::Occupied(OccupiedEntry { list: self, node }),
+            None => Entry::Vacant(VacantEntry { list: self, front: false }),
+        }
+    }
+
+    /// Returns a handle to the front element, if any.
+    pub fn front_handle(&self) -> Option<NodeHandle<T>> {
+        self.head.as_ref().map(|node| NodeHandle { node: Rc::downgrade(node) })
+    }
+
+    /// Returns a handle to the back element, if any.
+    pub fn back_handle(&self) -> Option<NodeHandle<T>> {
+        self.tail.as_ref().map(|node| NodeHandle { node: node.clone() })
+    }
+
+    /// Removes the element referred to by `handle` and returns it.
+    ///
+    /// Only `handle` and its clones are invalidated.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - A handle to an element of this list.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or `None` if the handle is stale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is still referenced from outside the list, and in
+    /// debug builds if the handle belongs to a different list.
+    pub fn remove(&mut self, handle: &NodeHandle<T>) -> Option<T> {
+        let node = handle.node.upgrade()?;
+        self.assert_owns(&node);
+        #[cfg(feature = "observer")]
+        let index = self.observer.as_ref().and_then(|_| self.nodes().position(|other| Rc::ptr_eq(&other, &node)));
+        match self.try_unlink(node) {
+            Ok(elem) => {
+                #[cfg(feature = "observer")]
+                if let Some(index) = index {
+                    self.notify(|_, observer| observer(ListEvent::Removed { index, elem: &elem }));
+                }
+                Some(elem)
+            }
+            Err(err) => panic!("remove failed: {}", err),
+        }
+    }
+
+    /// Moves the element referred to by `handle` to the front of the list.
+    ///
+    /// The node is relinked, so every handle stays valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - A handle to an element of this list.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the element was moved, `false` if the handle is stale.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the handle belongs to a different list.
+    pub fn move_to_front(&mut self, handle: &NodeHandle<T>) -> bool {
+        let node = match handle.node.upgrade() {
+            Some(node) => node,
+            None => return false,
+        };
+        self.assert_owns(&node);
+
+        self.detach(&node);
+        self.attach_front(node, "move_to_front");
+        self.stats.relinked(1);
+        true
+    }
+
+    /// Removes the element at `index` and moves the back element into its place.
+    ///
+    /// The back node itself is relinked at `index`, so its handles stay valid and
+    /// keep referring to it; only the handle of the removed element is invalidated.
+    /// The list is walked from whichever end is nearer, so this takes
+    /// O(min(index, len - index)) steps plus O(1) relinking. Removing the last
+    /// element is the same as [`LinkedList::pop_back`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the element to remove.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or `None` if `index >= len()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node at `index` is still referenced from outside the list.
+    pub fn swap_remove_back(&mut self, index: usize) -> Option<T> {
+        let length = self.len();
+        if index >= length {
+            return None;
+        }
+        if index + 1 == length {
+            return self.pop_back();
+        }
+
+        let back = self.tail.as_ref().and_then(|weak| weak.upgrade())?;
+        let steps_back = length - 1 - index;
+        let target = if index <= steps_back {
+            self.stats.traversed(index);
+            self.nodes().nth(index)?
+        } else {
+            self.stats.traversed(steps_back);
+            let mut node = Rc::clone(&back);
+            for _ in 0..steps_back {
+                let prev = node.prev.borrow().as_ref().and_then(|weak| weak.upgrade());
+                node = prev?;
+            }
+            node
+        };
+        self.open_ring();
+        if Rc::strong_count(&target) > 2 {
+            self.close_ring();
+            panic!("swap_remove_back failed: {}", PopError::Shared);
+        }
+
+        self.detach(&back);
+        self.attach_before(&target, back, "swap_remove_back");
+        self.stats.relinked(1);
+        match self.try_unlink(target) {
+            Ok(elem) => {
+                #[cfg(feature = "observer")]
+                self.notify(|_, observer| observer(ListEvent::Removed { index, elem: &elem }));
+                Some(elem)
+            }
+            Err(err) => panic!("swap_remove_back failed: {}", err),
+        }
+    }
+
+    /// Splits the list right after the element referred to by `handle`.
+    ///
+    /// The nodes are relinked, so every handle stays valid and belongs to whichever
+    /// list its node ends up in. The cut itself is O(1); a length-tracking list
+    /// also counts the nodes it moves, as do debug builds while retagging them.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - A handle to an element of this list.
+    ///
+    /// # Returns
+    ///
+    /// The elements after the handle's element, in order, or `None` if the handle
+    /// is stale. The list keeps the element itself and those before it.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the handle belongs to a different list.
+    pub fn split_off_after(&mut self, handle: &NodeHandle<T>) -> Option<LinkedList<T, TRACK_LEN>> {
+        let node = handle.node.upgrade()?;
+        self.assert_owns(&node);
+        if self.is_tail(&node) {
+            return Some(LinkedList::empty());
+        }
+        Some(self.split_between(&node, true))
+    }
+
+    /// Splits the list right before the element referred to by `handle`.
+    ///
+    /// This is the mirror image of [`LinkedList::split_off_after`], with the
+    /// same costs.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - A handle to an element of this list.
+    ///
+    /// # Returns
+    ///
+    /// The elements before the handle's element, in order, or `None` if the handle
+    /// is stale. The list keeps the element itself and those after it.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the handle belongs to a different list.
+    pub fn split_off_before(&mut self, handle: &NodeHandle<T>) -> Option<LinkedList<T, TRACK_LEN>> {
+        let node = handle.node.upgrade()?;
+        self.assert_owns(&node);
+        if self.head.as_ref().is_some_and(|head| Rc::ptr_eq(head, &node)) {
+            return Some(LinkedList::empty());
+        }
+        let prev = node.prev.borrow().as_ref().and_then(|weak| weak.upgrade())?;
+        Some(self.split_between(&prev, false))
+    }
+
+    /// Splits the list in two at `at`, returning the elements from that position on.
+    ///
+    /// Handles stay valid; those of the moved elements now belong to the returned list.
+    ///
+    /// # Arguments
+    ///
+    /// * `at` - The position of the first element to move; the list keeps the
+    ///   elements before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len()`.
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T, TRACK_LEN> {
+        let length = self.len();
+        assert!(at <= length, "split_off: index {} out of range for list of length {}", at, length);
+        if at == length {
+            return LinkedList::empty();
+        }
+        self.cut_range(at..length)
+    }
+
+    /// Cuts the list between `prev`, which must not be the tail, and the node after it.
+    ///
+    /// The list keeps the front part if `keep_front` is set and the back part
+    /// otherwise; the other part is returned as a new list.
+    fn split_between(&mut self, prev: &Rc<Node<T>>, keep_front: bool) -> LinkedList<T, TRACK_LEN> {
+        self.open_ring();
+        let mut other = LinkedList::empty();
+        let next = match prev.next.borrow_mut().take() {
+            Some(next) => next,
+            None => {
+                self.close_ring();
+                return other;
+            }
+        };
+        *next.prev.borrow_mut() = None;
+
+        let prev = Some(Rc::downgrade(prev));
+        if keep_front {
+            other.head = Some(next);
+            other.tail = std::mem::replace(&mut self.tail, prev);
+        } else {
+            other.head = self.head.replace(next);
+            other.tail = prev;
+        }
+
+        if TRACK_LEN || cfg!(debug_assertions) {
+            let moved = std::iter::successors(other.head.clone(), |node| node.next.borrow().clone()).count();
+            #[cfg(debug_assertions)]
+            for node in std::iter::successors(other.head.clone(), |node| node.next.borrow().clone()) {
+                node.owner.set(other.id);
+            }
+            other.length = if TRACK_LEN { moved } else { 0 };
+            self.shrink_length(moved);
+        }
+        self.close_ring();
+        self.stats.relinked(1);
+        other
+    }
+
+    /// Makes a single forward editing pass over the list.
+    ///
+    /// `f` is called once for each element, front to back, with a [`SpanEditor`]
+    /// positioned at it. The editor can replace or remove the element, insert new
+    /// elements after it and skip the elements that follow. Inserted and skipped
+    /// elements are not passed to `f`; the pass continues with the first element
+    /// after them. Only the handles of removed elements are invalidated.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Called with an editor at each visited element.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, every edit made so far is kept and the list stays consistent.
+    pub fn edit_spans<F: FnMut(&mut SpanEditor<'_, T, TRACK_LEN>)>(&mut self, mut f: F) {
+        let mut next = self.head.clone();
+        while let Some(node) = next {
+            let mut editor = SpanEditor {
+                anchor: Some(Rc::downgrade(&node)),
+                current: Some(node),
+                list: self,
+                skip: 0,
+            };
+            f(&mut editor);
+            let SpanEditor { anchor, skip, .. } = editor;
+
+            next = match anchor.and_then(|weak| weak.upgrade()) {
+                Some(anchor) => self.successor(&anchor),
+                None => self.head.clone(),
+            };
+            for _ in 0..skip {
+                match next {
+                    Some(node) => next = self.successor(&node),
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Returns a strong reference to the node after `node`; in ring mode the tail has none.
+    fn successor(&self, node: &Rc<Node<T>>) -> Option<Rc<Node<T>>> {
+        match self.circular && self.is_tail(node) {
+            true => None,
+            false => node.next.borrow().clone(),
+        }
+    }
+
+    /// Provides a cursor at the first element of the list.
+    ///
+    /// The cursor points at the "ghost" position if the list is empty.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, TRACK_LEN> {
+        let current = self.head.as_ref().map(Rc::downgrade);
+        CursorMut { list: self, current }
+    }
+
+    /// Provides a cursor at the last element of the list.
+    ///
+    /// The cursor points at the "ghost" position if the list is empty.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, TRACK_LEN> {
+        let current = self.tail.clone();
+        CursorMut { list: self, current }
+    }
+
+    /// Checks, in debug builds, that `node` belongs to this list.
+    #[cfg_attr(not(debug_assertions), allow(unused_variables))]
+    fn assert_owns(&self, node: &Rc<Node<T>>) {
+        #[cfg(debug_assertions)]
+        assert_eq!(node.owner.get(), self.id, "NodeHandle used with a list that does not own its node");
+    }
+
+    /// Links a detached node in front of the current head.
+    fn attach_front(&mut self, node: Rc<Node<T>>, operation: &str) {
+        self.open_ring();
+        self.grow_length(1, operation);
+        #[cfg(debug_assertions)]
+        node.owner.set(self.id);
+
+        match self.head.take() {
+            Some(old_head) => {
+                *old_head.prev.borrow_mut() = Some(Rc::downgrade(&node));
+                *node.next.borrow_mut() = Some(old_head);
+            }
+            None => {
+                self.tail = Some(Rc::downgrade(&node));
+            }
+        }
+        self.head = Some(node);
+        self.close_ring();
+    }
+
+    /// Links a detached node just before `anchor`, which must belong to this list.
+    fn attach_before(&mut self, anchor: &Rc<Node<T>>, node: Rc<Node<T>>, operation: &str) {
+        self.open_ring();
+        let prev = anchor.prev.borrow().as_ref().and_then(|weak| weak.upgrade());
+        let prev = match prev {
+            Some(prev) => prev,
+            None => return self.attach_front(node, operation),
+        };
+
+        self.grow_length(1, operation);
+        #[cfg(debug_assertions)]
+        node.owner.set(self.id);
+
+        *node.prev.borrow_mut() = Some(Rc::downgrade(&prev));
+        *anchor.prev.borrow_mut() = Some(Rc::downgrade(&node));
+        *node.next.borrow_mut() = prev.next.borrow_mut().take();
+        *prev.next.borrow_mut() = Some(node);
+        self.close_ring();
+    }
+
+    /// Links a detached node after the current tail.
+    fn attach_back(&mut self, node: Rc<Node<T>>, operation: &str) {
+        self.open_ring();
+        self.grow_length(1, operation);
+        #[cfg(debug_assertions)]
+        node.owner.set(self.id);
+
+        match self.tail.replace(Rc::downgrade(&node)).and_then(|weak| weak.upgrade()) {
+            Some(old_tail) => {
+                *node.prev.borrow_mut() = Some(Rc::downgrade(&old_tail));
+                *old_tail.next.borrow_mut() = Some(node);
+            }
+            None => {
+                self.head = Some(node);
+            }
+        }
+        self.close_ring();
+    }
+
+    /// Links the whole chain of `other` between the adjacent nodes `prev` and `next`.
+    ///
+    /// `None` stands for the start (as `prev`) or the end (as `next`) of the list.
+    /// This is O(1) apart from retagging the moved nodes in debug builds.
+    fn splice_between(
+        &mut self,
+        prev: Option<&Rc<Node<T>>>,
+        next: Option<Rc<Node<T>>>,
+        mut other: LinkedList<T, TRACK_LEN>,
+        operation: &str,
+    ) {
+        other.open_ring();
+        let (head, tail) = match (other.head.take(), other.tail.take().and_then(|weak| weak.upgrade())) {
+            (Some(head), Some(tail)) => (head, tail),
+            _ => return,
+        };
+        self.open_ring();
+        self.grow_length(other.length, operation);
+        other.length = 0;
+
+        #[cfg(debug_assertions)]
+        for node in std::iter::successors(Some(head.clone()), |node| node.next.borrow().clone()) {
+            node.owner.set(self.id);
+        }
+
+        match next {
+            Some(next) => {
+                *next.prev.borrow_mut() = Some(Rc::downgrade(&tail));
+                *tail.next.borrow_mut() = Some(next);
+            }
+            None => self.tail = Some(Rc::downgrade(&tail)),
+        }
+        match prev {
+            Some(prev) => {
+                *head.prev.borrow_mut() = Some(Rc::downgrade(prev));
+                *prev.next.borrow_mut() = Some(head);
+            }
+            None => self.head = Some(head),
+        }
+        self.close_ring();
+        self.stats.relinked(1);
+    }
+
+    /// Unlinks `node` from its neighbours, fixing up the head, tail, and length.
+    ///
+    /// The caller keeps `node` alive; its own links are cleared.
+    fn detach(&mut self, node: &Rc<Node<T>>) {
+        self.open_ring();
+        let prev = node.prev.borrow_mut().take().and_then(|weak| weak.upgrade());
+        let next = node.next.borrow_mut().take();
+        match &next {
+            Some(next_node) => *next_node.prev.borrow_mut() = prev.as_ref().map(Rc::downgrade),
+            None => self.tail = prev.as_ref().map(Rc::downgrade),
+        }
+        match prev {
+            Some(prev_node) => *prev_node.next.borrow_mut() = next,
+            None => self.head = next,
+        }
+        self.close_ring();
+
+        self.shrink_length(1);
+        #[cfg(debug_assertions)]
+        node.owner.set(0);
+    }
+
+    /// Relinks `nodes`, which must be exactly the nodes of this list, in the given order.
+    fn relink_in_order(&mut self, nodes: Vec<Rc<Node<T>>>) {
+        self.open_ring();
+        let mut prev: Option<&Rc<Node<T>>> = None;
+        for node in &nodes {
+            *node.prev.borrow_mut() = prev.map(Rc::downgrade);
+            if let Some(prev) = prev {
+                *prev.next.borrow_mut() = Some(node.clone());
+            }
+            prev = Some(node);
+        }
+        if let Some(last) = prev {
+            *last.next.borrow_mut() = None;
+        }
+        self.head = nodes.first().cloned();
+        self.tail = nodes.last().map(Rc::downgrade);
+        self.close_ring();
+        self.stats.relinked(nodes.len());
+    }
+
+    /// Unlinks the tail from the head of a ring, so that the chain is linear again.
+    ///
+    /// Every helper that relinks nodes opens the ring first and closes it once the
+    /// chain is consistent again; outside ring mode both are no-ops.
+    fn open_ring(&mut self) {
+        if !self.circular {
+            return;
+        }
+        if let Some(head) = &self.head {
+            *head.prev.borrow_mut() = None;
+        }
+        if let Some(tail) = self.tail.as_ref().and_then(|weak| weak.upgrade()) {
+            *tail.next.borrow_mut() = None;
+        }
+    }
+
+    /// Links the tail back to the head in ring mode.
+    fn close_ring(&mut self) {
+        if !self.circular {
+            return;
+        }
+        if let (Some(head), Some(tail)) = (&self.head, self.tail.as_ref().and_then(|weak| weak.upgrade())) {
+            *head.prev.borrow_mut() = Some(Rc::downgrade(&tail));
+            *tail.next.borrow_mut() = Some(head.clone());
+        }
+    }
+
+    /// Moves the data out of a node that has already been unlinked from the list.
+    ///
+    /// Callers must have checked that no other strong references remain.
+    fn unwrap_detached(node: Rc<Node<T>>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(node) => node.data.into_inner(),
+            Err(_) => unreachable!("detached node is still shared"),
+        }
+    }
+
+    /// Increases the length by `count`, panicking with the name of `operation` on overflow.
+    ///
+    /// Does nothing when the list does not track its length.
+    fn grow_length(&mut self, count: usize, operation: &str) {
+        if !TRACK_LEN {
+            return;
+        }
+        self.length = match self.length.checked_add(count) {
+            Some(length) => length,
+            None => panic!("{}: list length overflowed usize", operation),
+        };
+    }
+
+    /// Decreases the length by `count`, which must not exceed the current length.
+    fn shrink_length(&mut self, count: usize) {
+        if !TRACK_LEN {
+            return;
+        }
+        debug_assert!(self.length >= count, "list length accounting underflow");
+        self.length -= count;
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// This is O(1) for a length-tracking list and O(n) for an [`UncountedList`],
+    /// which has to count its nodes.
+    pub fn len(&self) -> usize {
+        if TRACK_LEN {
+            self.length
+        } else {
+            self.nodes().count()
+        }
+    }
+
+    /// Checks if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Clears the list, removing all elements.
+    ///
+    /// Every handle to an element of the list is invalidated.
+    pub fn clear(&mut self) {
+        #[cfg(feature = "stats")]
+        self.stats.freed(self.len());
+        let stats = std::mem::take(&mut self.stats);
+        let circular = self.circular;
+        #[cfg(feature = "observer")]
+        let observer = self.observer.take();
+        *self = Self::empty();
+        self.stats = stats;
+        self.circular = circular;
+        #[cfg(feature = "observer")]
+        {
+            self.observer = observer;
+            self.notify(|_, observer| observer(ListEvent::Cleared));
+        }
+    }
+
+    /// Registers `observer` to be told about changes to the list, replacing any
+    /// previous observer.
+    ///
+    /// The observer is called synchronously after each successful push, pop,
+    /// [`LinkedList::remove`], [`LinkedList::remove_first`] and [`LinkedList::clear`].
+    /// Other operations, such as sorting or splicing, are not reported. Reporting the
+    /// index of an element removed through a handle costs an extra O(n) walk.
+    ///
+    /// The list stays mutably borrowed while the observer runs, so the observer
+    /// cannot reach it again: a list kept in a `RefCell` panics with a
+    /// `BorrowMutError` if the observer tries to borrow it.
+    ///
+    /// # Arguments
+    ///
+    /// * `observer` - The callback receiving each [`ListEvent`].
+    #[cfg(feature = "observer")]
+    pub fn set_observer(&mut self, observer: impl FnMut(ListEvent<&T>) + 'static) {
+        self.observer = Some(Box::new(observer));
+    }
+
+    /// Removes the observer, if any; returns `true` if one was registered.
+    #[cfg(feature = "observer")]
+    pub fn remove_observer(&mut self) -> bool {
+        self.observer.take().is_some()
+    }
+
+    /// Passes the observer, if any, to `report` along with the list.
+    ///
+    /// The observer is taken out of the list for the call, so `report` can borrow
+    /// the list's elements while invoking it.
+    #[cfg(feature = "observer")]
+    fn notify<F: FnOnce(&Self, &mut dyn FnMut(ListEvent<&T>))>(&mut self, report: F) {
+        if let Some(mut observer) = self.observer.take() {
+            report(self, &mut *observer);
+            self.observer = Some(observer);
+        }
+    }
+
+    /// Returns the operation counters recorded since the list was created or last reset.
+    ///
+    /// See [`ListStats`](crate::stats::ListStats) for what each counter measures.
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::stats::ListStats {
+        self.stats.snapshot()
+    }
+
+    /// Sets every operation counter back to zero.
+    #[cfg(feature = "stats")]
+    pub fn reset_stats(&mut self) {
+        self.stats.reset();
+    }
+}
+
+/// Implementation of the Drop trait, opening a ring so that its nodes are freed.
+impl<T, const TRACK_LEN: bool> Drop for LinkedList<T, TRACK_LEN> {
+    fn drop(&mut self) {
+        self.open_ring();
+    }
+}
+
+impl<T, const TRACK_LEN: bool> Default for LinkedList<T, TRACK_LEN> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// Implementation of the Clone trait, producing an independent deep copy.
+///
+/// The copy is in ring mode if the list is; the observer is not copied.
+impl<T: Clone, const TRACK_LEN: bool> Clone for LinkedList<T, TRACK_LEN> {
+    fn clone(&self) -> Self {
+        let mut copy: Self = self.iter().cloned().collect();
+        if self.circular {
+            copy.make_circular();
+        }
+        copy
+    }
+}
+
+/// Implementation of the PartialEq trait, comparing the elements in order.
+///
+/// Counted lists of different lengths are told apart without walking either list.
+impl<T: PartialEq, const TRACK_LEN: bool> PartialEq for LinkedList<T, TRACK_LEN> {
+    fn eq(&self, other: &Self) -> bool {
+        (!TRACK_LEN || self.length == other.length) && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const TRACK_LEN: bool> Eq for LinkedList<T, TRACK_LEN> {}
+
+/// Implementation of the Hash trait, consistent with PartialEq.
+///
+/// The length is hashed before the elements, as for the singly list.
+impl<T: std::hash::Hash, const TRACK_LEN: bool> std::hash::Hash for LinkedList<T, TRACK_LEN> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
+/// Implementation of the PartialOrd trait, comparing the elements lexicographically.
+impl<T: PartialOrd, const TRACK_LEN: bool> PartialOrd for LinkedList<T, TRACK_LEN> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+/// Implementation of the Ord trait, comparing the elements lexicographically.
+impl<T: Ord, const TRACK_LEN: bool> Ord for LinkedList<T, TRACK_LEN> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Implementation of the FromIterator trait, so a list can be built with `collect()`.
+impl<T, const TRACK_LEN: bool> FromIterator<T> for LinkedList<T, TRACK_LEN> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::empty();
+        list.extend(iter);
+        list
+    }
+}
+
+/// Implementation of the Extend trait, appending the items at the back in order.
+impl<T, const TRACK_LEN: bool> Extend<T> for LinkedList<T, TRACK_LEN> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+/// Implementation of the Extend trait for references to `Copy` elements.
+impl<'a, T: Copy + 'a, const TRACK_LEN: bool> Extend<&'a T> for LinkedList<T, TRACK_LEN> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// Implementation of Debug trait to enable printing of the list for debugging purposes.
+///
+/// At most [`crate::DEBUG_LIMIT`] elements are printed unless a precision (`{:.N?}`)
+/// or the alternate flag (`{:#?}`) is given.
+impl<T: std::fmt::Debug, const TRACK_LEN: bool> std::fmt::Debug for LinkedList<T, TRACK_LEN> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let limit = if f.alternate() { usize::MAX } else { f.precision().unwrap_or(crate::DEBUG_LIMIT) };
+
+        for (shown, node) in self.nodes().enumerate() {
+            if shown == limit {
+                write!(f, ".. {} more <-> ", self.len() - shown)?;
+                break;
+            }
+            write!(f, "{:?} <-> ", *node.data.borrow())?;
+        }
+        write!(f, "End")
+    }
+}
+
+/// A view into one end of a `LinkedList`, which is either occupied or vacant.
+pub enum Entry<'a, T, const TRACK_LEN: bool = true> {
+    /// The end holds an element.
+    Occupied(OccupiedEntry<'a, T, TRACK_LEN>),
+    /// The list is empty.
+    Vacant(VacantEntry<'a, T, TRACK_LEN>),
+}
+
+/// A view into an element at one end of a `LinkedList`.
+pub struct OccupiedEntry<'a, T, const TRACK_LEN: bool = true> {
+    list: &'a mut LinkedList<T, TRACK_LEN>,
+    node: Rc<Node<T>>,
+}
+
+impl<T, const TRACK_LEN: bool> OccupiedEntry<'_, T, TRACK_LEN> {
+    /// Returns a reference to the element.
+    pub fn get(&self) -> Ref<'_, T> {
+        self.node.data.borrow()
+    }
+
+    /// Returns a mutable reference to the element.
+    pub fn get_mut(&mut self) -> RefMut<'_, T> {
+        self.node.data.borrow_mut()
+    }
+
+    /// Removes the element from the list and returns it.
+    ///
+    /// Only the handle of the removed element is invalidated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is still referenced from outside the list.
+    pub fn remove(self) -> T {
+        match self.list.try_unlink(self.node) {
+            Ok(elem) => elem,
+            Err(err) => panic!("remove failed: {}", err),
+        }
+    }
+
+    /// Inserts an element just before this one; the entry keeps referring to the same element.
+    ///
+    /// Every handle stays valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The data to insert.
+    pub fn insert_before(&mut self, elem: T) {
+        self.list.attach_before(&self.node, Node::new(elem), "insert_before");
+    }
+}
+
+/// A view into an end of an empty `LinkedList`.
+pub struct VacantEntry<'a, T, const TRACK_LEN: bool = true> {
+    list: &'a mut LinkedList<T, TRACK_LEN>,
+    front: bool,
+}
+
+impl<'a, T, const TRACK_LEN: bool> VacantEntry<'a, T, TRACK_LEN> {
+    /// Inserts an element into the empty list and returns a mutable reference to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The data to insert.
+    pub fn insert(self, elem: T) -> RefMut<'a, T> {
+        let list = self.list;
+        if self.front {
+            list.push_front(elem);
+        } else {
+            list.push_back(elem);
+        }
+
+        match &list.head {
+            Some(node) => node.data.borrow_mut(),
+            None => unreachable!("list is empty right after an insertion"),
+        }
+    }
+}
+
+/// Borrowing walk over the nodes of a `LinkedList`, guarded against cycles.
+struct NodeRefs<'a, T, const TRACK_LEN: bool> {
+    list: &'a LinkedList<T, TRACK_LEN>,
+    next: Option<&'a Node<T>>,
+    /// Nodes that may still be visited before the walk is known to be looping.
+    budget: usize,
+}
+
+impl<'a, T, const TRACK_LEN: bool> Iterator for NodeRefs<'a, T, TRACK_LEN> {
+    type Item = &'a Node<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next?;
+        self.budget = self.budget.checked_sub(1).unwrap_or_else(|| cycle_detected());
+        self.next = self.list.next_node(node);
+        Some(node)
+    }
+}
+
+/// An iterator over references to the elements of a `LinkedList`.
+///
+/// Every structural mutation of the list takes `&mut self`, handle-based ones
+/// included, so the borrow checker already rules out modifying a list while it
+/// is being iterated; no run-time check is needed:
+///
+/// ```compile_fail
+/// use linked_list::doubly::LinkedList;
+///
+/// let mut list = LinkedList::new();
+/// list.push_back(1);
+/// let handle = list.front_handle().unwrap();
+/// for _ in list.iter() {
+///     list.remove(&handle);
+/// }
+/// ```
+pub struct Iter<'a, T, const TRACK_LEN: bool = true> {
+    list: &'a LinkedList<T, TRACK_LEN>,
+    front: Option<&'a Node<T>>,
+    back: Option<&'a Node<T>>,
+    /// Elements left to yield for a counted list; also bounds the walk against cycles.
+    remaining: usize,
+}
+
+impl<T, const TRACK_LEN: bool> Clone for Iter<'_, T, TRACK_LEN> {
+    fn clone(&self) -> Self {
+        Iter { list: self.list, front: self.front, back: self.back, remaining: self.remaining }
+    }
+}
+
+impl<'a, T, const TRACK_LEN: bool> Iter<'a, T, TRACK_LEN> {
+    /// Consumes `node` from one end, ending the iteration once both ends have met.
+    fn take_step(&mut self, node: &'a Node<T>) {
+        self.remaining = self.remaining.checked_sub(1).unwrap_or_else(|| cycle_detected());
+        if self.front.is_some_and(|front| std::ptr::eq(front, node)) && self.back.is_some_and(|back| std::ptr::eq(back, node)) {
+            self.front = None;
+            self.back = None;
+        }
+    }
+
+    /// Consumes and returns the node at the front end.
+    fn step_front(&mut self) -> Option<&'a Node<T>> {
+        let node = self.front?;
+        self.take_step(node);
+        if self.front.is_some() {
+            self.front = self.list.next_node(node);
+        }
+        Some(node)
+    }
+
+    /// Consumes and returns the node at the back end.
+    fn step_back(&mut self) -> Option<&'a Node<T>> {
+        let node = self.back?;
+        self.take_step(node);
+        if self.back.is_some() {
+            self.back = self.list.prev_node(node);
+        }
+        Some(node)
+    }
+}
+
+impl<'a, T, const TRACK_LEN: bool> Iterator for Iter<'a, T, TRACK_LEN> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.step_front().map(|node| self.list.data_of(node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if TRACK_LEN {
+            (self.remaining, Some(self.remaining))
+        } else {
+            (usize::from(self.front.is_some()), None)
+        }
+    }
+}
+
+impl<T, const TRACK_LEN: bool> DoubleEndedIterator for Iter<'_, T, TRACK_LEN> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.step_back().map(|node| self.list.data_of(node))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// An iterator over mutable borrows of the elements of a `LinkedList`.
+///
+/// Each element sits in its own `RefCell`, so the items are `RefMut`s; they may be
+/// kept while the iteration goes on, since no two of them borrow the same element.
+pub struct IterMut<'a, T, const TRACK_LEN: bool = true> {
+    nodes: Iter<'a, T, TRACK_LEN>,
+}
+
+impl<'a, T, const TRACK_LEN: bool> Iterator for IterMut<'a, T, TRACK_LEN> {
+    type Item = RefMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nodes.step_front().map(|node| node.data.borrow_mut())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.nodes.size_hint()
+    }
+}
+
+impl<T, const TRACK_LEN: bool> DoubleEndedIterator for IterMut<'_, T, TRACK_LEN> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nodes.step_back().map(|node| node.data.borrow_mut())
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Iterator over the elements of a `LinkedList` with their neighbours.
+///
+/// Created by [`LinkedList::iter_with_neighbors`].
+pub struct Neighbors<'a, T, const TRACK_LEN: bool = true> {
+    nodes: NodeRefs<'a, T, TRACK_LEN>,
+}
+
+impl<'a, T, const TRACK_LEN: bool> Iterator for Neighbors<'a, T, TRACK_LEN> {
+    type Item = (Option<&'a T>, &'a T, Option<&'a T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let list = self.nodes.list;
+        let node = self.nodes.next()?;
+        let prev = list.prev_node(node).map(|prev| list.data_of(prev));
+        let next = self.nodes.next.map(|next| list.data_of(next));
+        Some((prev, list.data_of(node), next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if TRACK_LEN {
+            (self.nodes.budget, Some(self.nodes.budget))
+        } else {
+            (usize::from(self.nodes.next.is_some()), None)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Neighbors<'_, T> {}
+
+/// Aborts a traversal that has visited more nodes than the list holds.
+#[cold]
+fn cycle_detected() -> ! {
+    panic!("list structure corrupted (cycle detected)")
+}
+
+/// A cursor over a `LinkedList` that can edit the list around its position.
+///
+/// Besides the elements, the cursor can point at a "ghost" position between the
+/// back and the front of the list; moving past either end lands on it.
+pub struct CursorMut<'a, T, const TRACK_LEN: bool = true> {
+    list: &'a mut LinkedList<T, TRACK_LEN>,
+    /// The element under the cursor, or `None` at the ghost position.
+    ///
+    /// This is weak so that the cursor does not count as an external reference
+    /// when elements are later moved out of the list.
+    current: Option<Weak<Node<T>>>,
+}
+
+impl<T, const TRACK_LEN: bool> CursorMut<'_, T, TRACK_LEN> {
+    /// Returns a mutable reference to the element under the cursor, if any.
+    pub fn current(&mut self) -> Option<RefMut<'_, T>> {
+        let node = self.current.as_ref().filter(|weak| weak.strong_count() > 0)?;
+        // SAFETY: the cursor borrows the list mutably and the list owns the node,
+        // so the node outlives the returned borrow.
+        let node = unsafe { &*node.as_ptr() };
+        Some(node.data.borrow_mut())
+    }
+
+    /// Returns a strong reference to the element under the cursor, if any.
+    fn current_node(&self) -> Option<Rc<Node<T>>> {
+        self.current.as_ref().and_then(|weak| weak.upgrade())
+    }
+
+    /// Moves the cursor to the next element, or from the last element to the ghost position.
+    ///
+    /// From the ghost position, the cursor moves to the front of the list. In ring
+    /// mode the last element is followed directly by the first.
+    pub fn move_next(&mut self) {
+        self.current = match self.current_node() {
+            Some(node) => node.next.borrow().as_ref().map(Rc::downgrade),
+            None => self.list.head.as_ref().map(Rc::downgrade),
+        };
+    }
+
+    /// Moves the cursor to the previous element, or from the first element to the ghost position.
+    ///
+    /// From the ghost position, the cursor moves to the back of the list. In ring
+    /// mode the first element is preceded directly by the last.
+    pub fn move_prev(&mut self) {
+        self.current = match self.current_node() {
+            Some(node) => node.prev.borrow().clone(),
+            None => self.list.tail.clone(),
+        };
+    }
+
+    /// Returns the element under the cursor and the one [`CursorMut::move_next`] would reach.
+    ///
+    /// At the ghost position the first is `None` and the second is the front of
+    /// the list; after the last element the second is `None`, except in ring mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either element is borrowed mutably, e.g. through [`CursorMut::current`].
+    pub fn peek_two(&self) -> (Option<&T>, Option<&T>) {
+        // SAFETY: as for `current`, the cursor borrows the list and the list owns the node.
+        let current = self.current.as_ref().filter(|weak| weak.strong_count() > 0).map(|weak| unsafe { &*weak.as_ptr() });
+        let next = match current {
+            // SAFETY: `next` links are only rewritten through the list, which the cursor borrows.
+            Some(node) => unsafe { node.next.try_borrow_unguarded() }.ok().and_then(|next| next.as_deref()),
+            None => self.list.head.as_deref(),
+        };
+        (current.map(|node| self.list.data_of(node)), next.map(|node| self.list.data_of(node)))
+    }
+
+    /// Removes the element under the cursor if `pred` accepts it.
+    ///
+    /// On removal the cursor moves on as [`CursorMut::move_next`] would, to the
+    /// ghost position if no element is left there. Only the removed element's
+    /// handle is invalidated.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Decides whether the current element is consumed.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or `None` if the cursor is at the ghost position or
+    /// `pred` rejected the element, in which case the cursor does not move.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is still referenced from outside the list.
+    pub fn consume_if<P: FnOnce(&T) -> bool>(&mut self, pred: P) -> Option<T> {
+        let node = self.current_node()?;
+        if !pred(&node.data.borrow()) {
+            return None;
+        }
+
+        let next = node.next.borrow().as_ref().filter(|next| !Rc::ptr_eq(next, &node)).map(Rc::downgrade);
+        #[cfg(feature = "observer")]
+        let index = self.list.observer.as_ref().and_then(|_| self.list.nodes().position(|other| Rc::ptr_eq(&other, &node)));
+        match self.list.try_unlink(node) {
+            Ok(elem) => {
+                self.current = next;
+                #[cfg(feature = "observer")]
+                if let Some(index) = index {
+                    self.list.notify(|_, observer| observer(ListEvent::Removed { index, elem: &elem }));
+                }
+                Some(elem)
+            }
+            Err(err) => panic!("consume_if failed: {}", err),
+        }
+    }
+
+    /// Inserts every element of `other` just before the cursor in O(1).
+    ///
+    /// At the ghost position the elements are appended to the back of the list.
+    /// The cursor keeps pointing at the same element, and handles to elements of
+    /// either list stay valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to splice in, in order.
+    pub fn splice_before(&mut self, other: LinkedList<T, TRACK_LEN>) {
+        self.list.open_ring();
+        let current = self.current_node();
+        let prev = match &current {
+            Some(node) => node.prev.borrow().as_ref().and_then(|weak| weak.upgrade()),
+            None => self.list.tail.as_ref().and_then(|weak| weak.upgrade()),
+        };
+        self.list.splice_between(prev.as_ref(), current, other, "splice_before");
+        self.list.close_ring();
+    }
+
+    /// Inserts every element of `other` just after the cursor in O(1).
+    ///
+    /// At the ghost position the elements are prepended to the front of the list.
+    /// The cursor keeps pointing at the same element, and handles to elements of
+    /// either list stay valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to splice in, in order.
+    pub fn splice_after(&mut self, other: LinkedList<T, TRACK_LEN>) {
+        self.list.open_ring();
+        let current = self.current_node();
+        let next = match &current {
+            Some(node) => node.next.borrow().clone(),
+            None => self.list.head.clone(),
+        };
+        self.list.splice_between(current.as_ref(), next, other, "splice_after");
+        self.list.close_ring();
+    }
+}
+
+/// Edits the list at one element during [`LinkedList::edit_spans`].
+pub struct SpanEditor<'a, T, const TRACK_LEN: bool = true> {
+    list: &'a mut LinkedList<T, TRACK_LEN>,
+    /// The element being visited, or `None` once it has been removed.
+    current: Option<Rc<Node<T>>>,
+    /// The node new elements are inserted after, or `None` for the front of the list.
+    ///
+    /// This is the current element, the last element inserted after it, or the
+    /// element before it once it has been removed.
+    anchor: Option<Weak<Node<T>>>,
+    /// How many elements after the anchor the pass skips.
+    skip: usize,
+}
+
+impl<T, const TRACK_LEN: bool> SpanEditor<'_, T, TRACK_LEN> {
+    /// Returns a mutable reference to the current element, or `None` if it was removed.
+    pub fn current(&mut self) -> Option<RefMut<'_, T>> {
+        self.current.as_ref().map(|node| node.data.borrow_mut())
+    }
+
+    /// Replaces the current element with `elem`, returning the old element.
+    ///
+    /// The node is kept, so a handle to the element stays valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current element was removed.
+    pub fn replace_current(&mut self, elem: T) -> T {
+        match &self.current {
+            Some(node) => std::mem::replace(&mut *node.data.borrow_mut(), elem),
+            None => panic!("replace_current: the current element was removed"),
+        }
+    }
+
+    /// Removes the current element and returns it, or `None` if it was already removed.
+    ///
+    /// Elements inserted afterwards take its place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is still referenced from outside the list.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current.take()?;
+        if self.anchor.as_ref().is_some_and(|anchor| ptr::eq(anchor.as_ptr(), Rc::as_ptr(&node))) {
+            let is_head = self.list.head.as_ref().is_some_and(|head| Rc::ptr_eq(head, &node));
+            self.anchor = if is_head { None } else { node.prev.borrow().clone() };
+        }
+        match self.list.try_unlink(node) {
+            Ok(elem) => Some(elem),
+            Err(err) => panic!("remove_current failed: {}", err),
+        }
+    }
+
+    /// Inserts `elem` after the current element and the elements inserted before it.
+    ///
+    /// Inserted elements are not visited by the pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The data to insert.
+    pub fn insert_after(&mut self, elem: T) {
+        let node = Node::new(elem);
+        let anchor = self.anchor.replace(Rc::downgrade(&node)).and_then(|weak| weak.upgrade());
+        match anchor {
+            Some(anchor) => match self.list.successor(&anchor) {
+                Some(next) => self.list.attach_before(&next, node, "insert_after"),
+                None => self.list.attach_back(node, "insert_after"),
+            },
+            None => self.list.attach_front(node, "insert_after"),
+        }
+        self.list.stats.allocated(1);
+    }
+
+    /// Skips the next `n` elements of the list, which are then not passed to the closure.
+    ///
+    /// Calls add up. Skipping past the end of the list ends the pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of elements to skip.
+    pub fn skip(&mut self, n: usize) {
+        self.skip = self.skip.saturating_add(n);
+    }
+}
+
+/// An owning iterator over the elements of a `LinkedList`.
+pub struct IntoIter<T, const TRACK_LEN: bool = true> {
+    list: LinkedList<T, TRACK_LEN>,
+}
+
+impl<T, const TRACK_LEN: bool> Iterator for IntoIter<T, TRACK_LEN> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if TRACK_LEN {
+            (self.list.len(), Some(self.list.len()))
+        } else {
+            (usize::from(!self.list.is_empty()), None)
+        }
+    }
+}
+
+impl<T, const TRACK_LEN: bool> DoubleEndedIterator for IntoIter<T, TRACK_LEN> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T, const TRACK_LEN: bool> IntoIterator for LinkedList<T, TRACK_LEN> {
+    type Item = T;
+    type IntoIter = IntoIter<T, TRACK_LEN>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T, const TRACK_LEN: bool> IntoIterator for &'a LinkedList<T, TRACK_LEN> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, TRACK_LEN>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const TRACK_LEN: bool> IntoIterator for &'a mut LinkedList<T, TRACK_LEN> {
+    type Item = RefMut<'a, T>;
+    type IntoIter = IterMut<'a, T, TRACK_LEN>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Collects elements on one thread for a doubly list that is built on another.
+///
+/// [`LinkedList`] links its nodes with `Rc`, so it cannot be sent between threads.
+/// The builder keeps the elements in a `Vec` instead, which is `Send` whenever `T`
+/// is, and [`ListBuilder::build`] links them into a list on the receiving thread.
+#[derive(Debug, Clone)]
+pub struct ListBuilder<T> {
+    elems: Vec<T>,
+}
+
+impl<T> ListBuilder<T> {
+    /// Constructs a new, empty `ListBuilder`.
+    pub fn new() -> Self {
+        ListBuilder { elems: Vec::new() }
+    }
+
+    /// Appends an element to the list being built.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The element to be appended.
+    pub fn push_back(&mut self, elem: T) {
+        self.elems.push(elem);
+    }
+
+    /// Returns the number of elements collected so far.
+    pub fn len(&self) -> usize {
+        self.elems.len()
+    }
+
+    /// Checks if no element has been collected.
+    pub fn is_empty(&self) -> bool {
+        self.elems.is_empty()
+    }
+
+    /// Links the collected elements into a list, in one O(n) pass.
+    ///
+    /// # Returns
+    ///
+    /// A list holding the elements in the order they were pushed.
+    pub fn build(self) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        for elem in self.elems {
+            list.push_back(elem);
+        }
+        list
+    }
+}
+
+impl<T> Default for ListBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_and_pop_front() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.pop_front(), None);
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), None);
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_push_and_pop_back() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.pop_back(), None);
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_mixed_push_pop() {
+        let mut list = LinkedList::new();
+
+        list.push_front(1);
+        list.push_back(2);
+        list.push_front(3);
+        list.push_back(4);
+
+        // Expected list: 3 -> 1 -> 2 -> 4
+
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_length_after_operations() {
+        let mut list = LinkedList::new();
+
+        list.push_front(1);
+        list.push_back(2);
+        assert_eq!(list.len(), 2); // Ensure `len` method exists and works as expected
+
+        list.pop_front();
+        assert_eq!(list.len(), 1);
+
+        list.pop_back();
+        assert_eq!(list.len(), 0);
+
+        assert_links(&list);
+    }
+    
+    #[test]
+    fn test_clear() {
+        let mut list = LinkedList::new();
+
+        list.push_front(1);
+        list.push_back(2);
+        list.clear();
+
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_length_after_pop_back() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        list.pop_back();
+        assert_eq!(list.len(), 2);
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_try_pop_front_shared() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+
+        let handle = list.head.clone().unwrap();
+        assert_eq!(list.strong_count_front(), Some(2));
+        assert_eq!(list.try_pop_front(), Err(PopError::Shared));
+        assert_eq!(list.len(), 2);
+
+        drop(handle);
+        assert_eq!(list.strong_count_front(), Some(1));
+        assert_eq!(list.try_pop_front(), Ok(1));
+        assert_eq!(list.try_pop_front(), Ok(2));
+        assert_eq!(list.try_pop_front(), Err(PopError::Empty));
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_try_pop_back_shared() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+
+        let handle = list.tail.as_ref().and_then(|weak| weak.upgrade()).unwrap();
+        assert_eq!(list.strong_count_back(), Some(2));
+        assert_eq!(list.try_pop_back(), Err(PopError::Shared));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.strong_count_back(), Some(2));
+
+        drop(handle);
+        assert_eq!(list.strong_count_back(), Some(1));
+        assert_eq!(list.try_pop_back(), Ok(2));
+        assert_eq!(list.try_pop_back(), Ok(1));
+        assert_eq!(list.try_pop_back(), Err(PopError::Empty));
+        assert_eq!(list.strong_count_back(), None);
+
+        assert_links(&list);
+    }
+
+    #[test]
+    #[should_panic(expected = "pop_back failed")]
+    fn test_pop_back_shared_panics() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+
+        let _handle = list.head.clone();
+        list.pop_back();
+    }
+
+    #[test]
+    fn test_pop_error_display() {
+        assert_eq!(PopError::Empty.to_string(), "the list is empty");
+        assert_eq!(PopError::Shared.to_string(), "the node has external strong references");
+    }
+
+    #[test]
+    fn test_remove_first() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(2);
+
+        assert_eq!(list.remove_first(&2), Some(2));
+        assert_eq!(list.remove_first(&5), None);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.remove_first(&2), Some(2));
+        assert_eq!(list.remove_first(&1), Some(1));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), None);
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_search_strings_with_str() {
+        let mut list = LinkedList::new();
+        list.push_back(String::from("a"));
+        list.push_back(String::from("b"));
+        list.push_back(String::from("a"));
+
+        assert!(list.contains("b"));
+        assert!(!list.contains("c"));
+        assert_eq!(list.count_of("a"), 2);
+        assert_eq!(list.find_seq(["b", "a"]), Some(1));
+        assert_eq!(list.remove_first("a"), Some(String::from("a")));
+        assert_eq!(list.find_seq(["b", "a"]), Some(0));
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_search_bytes_with_slice() {
+        let mut list = LinkedList::new();
+        list.push_back(vec![1u8, 2]);
+        list.push_back(vec![3u8]);
+
+        assert!(list.contains(&[3u8][..]));
+        assert_eq!(list.count_of(&[1u8, 2][..]), 1);
+        assert_eq!(list.find_seq([&[3u8][..]]), Some(1));
+        assert_eq!(list.remove_first(&[1u8, 2][..]), Some(vec![1, 2]));
+        assert_eq!(list.len(), 1);
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_search_integers() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert!(list.contains(&3));
+        assert_eq!(list.count_of(&2), 2);
+        assert_eq!(list.find_seq(&[2, 3]), Some(3));
+        assert_eq!(list.find_seq(&[3, 4]), None);
+        assert_eq!(list.find_seq(&[]), Some(0));
+
+        assert_links(&list);
+    }
+
+    /// Payload that counts how many times it has been dropped.
+    struct DropCounter {
+        value: i32,
+        drops: Rc<std::cell::Cell<usize>>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_links(&list);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_try_map_all_ok() {
+        let mut list = LinkedList::new();
+        list.push_back("1");
+        list.push_back("2");
+        list.push_back("3");
+
+        assert_links(&list);
+        let mut mapped = list.try_map(|s| s.parse::<i32>()).ok().unwrap();
+        assert_eq!(mapped.len(), 3);
+        assert_eq!(mapped.pop_front(), Some(1));
+        assert_eq!(mapped.pop_back(), Some(3));
+        assert_eq!(mapped.pop_back(), Some(2));
+    }
+
+    #[test]
+    fn test_try_map_errors() {
+        let mut list = LinkedList::new();
+        list.push_back("x");
+        list.push_back("2");
+        assert_eq!(list.try_map(|s| s.parse::<i32>()).err().map(|(index, _)| index), Some(0));
+
+        let mut list = LinkedList::new();
+        list.push_back("1");
+        list.push_back("x");
+        list.push_back("3");
+        assert_links(&list);
+        assert_eq!(list.try_map(|s| s.parse::<i32>()).err().map(|(index, _)| index), Some(1));
+    }
+
+    #[test]
+    fn test_try_map_drop_accounting() {
+        let drops = Rc::new(std::cell::Cell::new(0));
+        let mut list = LinkedList::new();
+        for value in 0..5 {
+            list.push_back(DropCounter { value, drops: drops.clone() });
+        }
+
+        let converted = Rc::new(std::cell::Cell::new(0));
+        assert_links(&list);
+        let result = list.try_map(|elem| {
+            if elem.value == 2 {
+                return Err("two");
+            }
+            Ok(DropCounter { value: elem.value * 10, drops: converted.clone() })
+        });
+
+        assert_eq!(result.err(), Some((2, "two")));
+        assert_eq!(drops.get(), 5);
+        assert_eq!(converted.get(), 2);
+    }
+
+    /// Drives random operations on lists made by `new_list`, comparing against a `VecDeque`.
+    fn check_against_vec_deque<const TRACK_LEN: bool>(new_list: fn() -> LinkedList<i32, TRACK_LEN>) {
+        let mut rng = crate::testing::XorShift::new(0xd0b1);
+        for _ in 0..20 {
+            let mut list = new_list();
+            let mut oracle = std::collections::VecDeque::new();
+
+            for _ in 0..200 {
+                let value = rng.below(16) as i32;
+                match rng.below(7) {
+                    0 => {
+                        list.push_front(value);
+                        oracle.push_front(value);
+                    }
+                    1 => {
+                        list.push_back(value);
+                        oracle.push_back(value);
+                    }
+                    2 => assert_eq!(list.pop_front(), oracle.pop_front()),
+                    3 => assert_eq!(list.pop_back(), oracle.pop_back()),
+                    4 => {
+                        let expected = oracle.iter().position(|x| *x == value).and_then(|i| oracle.remove(i));
+                        assert_eq!(list.remove_first(&value), expected);
+                    }
+                    5 => {
+                        let n = rng.below(8);
+                        list.rotate(n);
+                        if !oracle.is_empty() {
+                            let steps = n % oracle.len();
+                            oracle.rotate_left(steps);
+                        }
+                    }
+                    _ => {
+                        if rng.below(10) == 0 {
+                            list.clear();
+                            oracle.clear();
+                        }
+                    }
+                }
+
+                assert_eq!(list.len(), oracle.len());
+                assert_eq!(list.is_empty(), oracle.is_empty());
+                assert!(list.nodes().map(|node| *node.data.borrow()).eq(oracle.iter().copied()));
+                assert!(list.iter().rev().eq(oracle.iter().rev()));
+                assert_links(&list);
+            }
+        }
+    }
+
+    #[test]
+    fn test_model_against_vec_deque() {
+        check_against_vec_deque(LinkedList::new);
+    }
+
+    #[test]
+    fn test_uncounted_model_against_vec_deque() {
+        check_against_vec_deque(UncountedList::uncounted);
+    }
+
+    #[test]
+    fn test_ring_model_against_vec_deque() {
+        check_against_vec_deque(|| {
+            let mut list = LinkedList::new();
+            list.make_circular();
+            list
+        });
+        check_against_vec_deque(|| {
+            let mut list = UncountedList::uncounted();
+            list.make_circular();
+            list
+        });
+    }
+
+    #[test]
+    fn test_uncounted_list() {
+        let mut list = UncountedList::uncounted();
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.get_clamped(3), None);
+
+        for i in 0..3 {
+            list.push_back(i);
+        }
+        let mut other = UncountedList::uncounted();
+        for i in 3..6 {
+            other.push_back(i);
+        }
+        #[cfg(feature = "stats")]
+        list.reset_stats();
+        list.extend_list(other);
+        #[cfg(feature = "stats")]
+        assert_eq!(list.stats().traversal_steps, 0);
+        assert_eq!(list.length, 0);
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.get_clamped(1), Some(&1));
+        assert_eq!(list.get_clamped(9), Some(&5));
+        assert_eq!(format!("{:.2?}", list), "0 <-> 1 <-> .. 4 more <-> End");
+
+        assert_links(&list);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.size_hint(), (1, None));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_handles() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        let first = list.back_handle().unwrap();
+        list.push_back(2);
+        let second = list.back_handle().unwrap();
+        list.push_back(3);
+
+        assert!(list.move_to_front(&second));
+        assert_eq!(first.get(), Some(1));
+        assert_eq!(list.front_handle().unwrap().get(), Some(2));
+
+        assert_eq!(list.remove(&first), Some(1));
+        assert!(!first.is_valid());
+        assert_eq!(list.remove(&first), None);
+        assert!(!list.move_to_front(&first));
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+        assert!(!second.is_valid());
+
+        assert_links(&list);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "NodeHandle used with a list that does not own its node")]
+    fn test_handle_from_other_list_panics() {
+        let mut a = LinkedList::new();
+        a.push_back(1);
+        let handle = a.front_handle().unwrap();
+
+        let mut b = LinkedList::new();
+        b.push_back(2);
+        b.remove(&handle);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "NodeHandle used with a list that does not own its node")]
+    fn test_move_to_front_from_other_list_panics() {
+        let mut a = LinkedList::new();
+        a.push_back(1);
+        let handle = a.front_handle().unwrap();
+
+        let mut b = LinkedList::<i32>::new();
+        b.move_to_front(&handle);
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn test_node_size_without_ownership_tag() {
+        struct Untagged {
+            _data: RefCell<u64>,
+            _prev: RefCell<Option<Weak<Node<u64>>>>,
+            _next: RefCell<Option<Rc<Node<u64>>>>,
+        }
+
+        assert_eq!(std::mem::size_of::<Node<u64>>(), std::mem::size_of::<Untagged>());
+    }
+
+    #[test]
+    fn test_front_entry() {
+        let mut list = LinkedList::new();
+        match list.front_entry() {
+            Entry::Vacant(entry) => *entry.insert(1) += 10,
+            Entry::Occupied(_) => panic!("empty list has an occupied front"),
+        }
+        assert_eq!(list.len(), 1);
+
+        match list.front_entry() {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(*entry.get(), 11);
+                *entry.get_mut() = 2;
+                entry.insert_before(1);
+                assert_eq!(*entry.get(), 2);
+            }
+            Entry::Vacant(_) => panic!("non-empty list has a vacant front"),
+        }
+        assert_eq!(list.len(), 2);
+
+        match list.front_entry() {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), 1),
+            Entry::Vacant(_) => panic!("non-empty list has a vacant front"),
+        }
+        assert_eq!(list.pop_back(), Some(2));
+        assert!(list.is_empty());
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_back_entry() {
+        let mut list = LinkedList::new();
+        match list.back_entry() {
+            Entry::Vacant(entry) => *entry.insert(3) *= 2,
+            Entry::Occupied(_) => panic!("empty list has an occupied back"),
+        }
+        list.push_front(1);
+
+        match list.back_entry() {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(*entry.get(), 6);
+                *entry.get_mut() += 1;
+                entry.insert_before(5);
+                assert_eq!(*entry.get(), 7);
+            }
+            Entry::Vacant(_) => panic!("non-empty list has a vacant back"),
+        }
+        assert_eq!(list.len(), 3);
+
+        match list.back_entry() {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), 7),
+            Entry::Vacant(_) => panic!("non-empty list has a vacant back"),
+        }
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(5));
+        assert_eq!(list.pop_front(), None);
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut list = LinkedList::new();
+        assert_eq!(format!("{:?}", list), "End");
+
+        list.push_back(1);
+        list.push_back(2);
+        assert_eq!(format!("{:?}", list), "1 <-> 2 <-> End");
+
+        for i in 3..=1000 {
+            list.push_back(i);
+        }
+        let expected: Vec<_> = (1..=32).map(|i| format!("{} <-> ", i)).collect();
+        assert_eq!(format!("{:?}", list), format!("{}.. 968 more <-> End", expected.concat()));
+        assert_eq!(format!("{:.1?}", list), "1 <-> .. 999 more <-> End");
+        assert_eq!(format!("{:#?}", list).matches("<->").count(), 1000);
+
+        assert_links(&list);
+    }
+
+    /// Payload whose `Clone` implementation panics, proving that conversions never clone.
+    #[derive(Debug, PartialEq)]
+    struct NoClone(i32);
+
+    impl Clone for NoClone {
+        fn clone(&self) -> Self {
+            panic!("NoClone was cloned");
+        }
+    }
+
+    #[test]
+    fn test_into_singly_round_trip() {
+        let mut list = LinkedList::new();
+        for i in 0..4 {
+            list.push_back(NoClone(i));
+        }
+
+        assert_links(&list);
+        let singly = list.into_singly();
+        assert_eq!(singly.len(), 4);
+        assert_eq!(singly.iter().map(|elem| elem.0).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+        let mut doubly = singly.into_doubly();
+        assert_eq!(doubly.len(), 4);
+        assert_eq!(doubly.pop_front(), Some(NoClone(0)));
+        assert_eq!(doubly.pop_back(), Some(NoClone(3)));
+
+        assert!(LinkedList::<NoClone>::new().into_singly().is_empty());
+    }
+
+    #[test]
+    fn test_get_clamped() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.get_clamped(0), None);
+        assert_eq!(list.get_clamped(usize::MAX), None);
+
+        for i in 0..5 {
+            list.push_back(i);
+        }
+        for i in 0..5 {
+            assert_eq!(list.get_clamped(i), Some(&i));
+        }
+        assert_eq!(list.get_clamped(5), Some(&4));
+        assert_eq!(list.get_clamped(usize::MAX), Some(&4));
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_extend_list() {
+        let mut list = LinkedList::new();
+        list.extend_list(LinkedList::new());
+        assert!(list.is_empty());
+
+        let mut other = LinkedList::new();
+        other.push_back(NoClone(1));
+        other.push_back(NoClone(2));
+        list.extend_list(other);
+
+        let mut other = LinkedList::new();
+        for i in 3..6 {
+            other.push_back(NoClone(i));
+        }
+        let handle = other.back_handle().unwrap();
+        list.extend_list(other);
+        list.extend_list(LinkedList::new());
+        assert_eq!(list.len(), 5);
+
+        assert_eq!(list.remove(&handle), Some(NoClone(5)));
+        assert_eq!(list.pop_back(), Some(NoClone(4)));
+        assert_eq!(list.pop_front(), Some(NoClone(1)));
+        assert_links(&list);
+        assert_eq!(list.into_iter().map(|elem| elem.0).collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_map_in_place() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.map_in_place(|_| panic!("called on an empty list"));
+
+        for i in 0..5 {
+            list.push_back(i);
+        }
+        let mut visited = Vec::new();
+        list.map_in_place(|elem| {
+            visited.push(*elem);
+            *elem += 1;
+        });
+        assert_eq!(visited, vec![0, 1, 2, 3, 4]);
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(5));
+        assert_links(&list);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_map_in_place_pushes_elsewhere() {
+        let mut list = LinkedList::new();
+        for i in 0..3 {
+            list.push_back(i);
+        }
+
+        let mut other = LinkedList::new();
+        list.map_in_place(|elem| {
+            other.push_front(*elem);
+            *elem *= 10;
+        });
+        assert_links(&list);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 10, 20]);
+        assert_eq!(other.into_iter().collect::<Vec<_>>(), vec![2, 1, 0]);
+    }
+
+    /// Checks every structural invariant of `list`: the links agree in both directions,
+    /// `head` and `tail` are the ends of the chain, and `length` matches in counted mode.
+    fn assert_links<T, const TRACK_LEN: bool>(list: &LinkedList<T, TRACK_LEN>) {
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    /// Builds a list from `elems`.
+    fn list_of<const TRACK_LEN: bool>(elems: &[i32]) -> LinkedList<i32, TRACK_LEN> {
+        let mut list = LinkedList::empty();
+        for elem in elems {
+            list.push_back(*elem);
+        }
+        list
+    }
+
+    #[test]
+    fn test_cursor_moves() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.current().as_deref(), Some(&1));
+        cursor.move_prev();
+        assert!(cursor.current().is_none());
+        cursor.move_prev();
+        assert_eq!(cursor.current().as_deref(), Some(&3));
+        *cursor.current().unwrap() = 30;
+        cursor.move_next();
+        assert!(cursor.current().is_none());
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current().as_deref(), Some(&2));
+
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.current().as_deref(), Some(&30));
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        assert!(cursor.current().is_none());
+        cursor.move_next();
+        assert!(cursor.current().is_none());
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_cursor_splice() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.splice_before(list_of(&[10, 11]));
+        cursor.splice_after(list_of(&[20, 21]));
+        cursor.splice_after(LinkedList::new());
+        assert_eq!(cursor.current().as_deref(), Some(&2));
+        assert_links(&list);
+        assert_eq!(list.len(), 7);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 10, 11, 2, 20, 21, 3]);
+
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.splice_before(list_of(&[0]));
+        cursor.move_next();
+        cursor.move_next();
+        cursor.splice_after(list_of(&[4]));
+        assert_eq!(cursor.current().as_deref(), Some(&3));
+        assert_links(&list);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_cursor_splice_at_ghost() {
+        let mut list: LinkedList<i32> = list_of(&[2, 3]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_prev();
+        cursor.splice_before(list_of(&[4, 5]));
+        cursor.splice_after(list_of(&[0, 1]));
+        assert!(cursor.current().is_none());
+        assert_links(&list);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        cursor.splice_after(LinkedList::new());
+        cursor.splice_before(list_of(&[1, 2]));
+        cursor.splice_after(list_of(&[0]));
+        assert_links(&empty);
+        assert_eq!(empty.len(), 3);
+        assert_eq!(empty.into_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_cursor_splice_uncounted() {
+        let mut list: UncountedList<i32> = list_of(&[1, 4]);
+        #[cfg(feature = "stats")]
+        list.reset_stats();
+        let mut cursor = list.cursor_back_mut();
+        cursor.splice_before(list_of(&[2, 3]));
+        cursor.splice_after(list_of(&[5, 6]));
+        #[cfg(feature = "stats")]
+        assert_eq!(list.stats().traversal_steps, 0);
+        assert_eq!(list.length, 0);
+        assert_eq!(list.len(), 6);
+        assert_links(&list);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_spliced_nodes_are_retagged() {
+        let mut list: LinkedList<i32> = list_of(&[1]);
+        let other: LinkedList<i32> = list_of(&[2]);
+        let handle = other.front_handle().unwrap();
+        list.cursor_front_mut().splice_after(other);
+        assert_eq!(list.remove(&handle), Some(2));
+
+        assert_links(&list);
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_stats() {
+        use crate::stats::ListStats;
+
+        let mut list: LinkedList<i32> = list_of(&[0, 1, 2, 3, 4, 5]);
+        assert_eq!(list.stats(), ListStats { allocations: 6, ..ListStats::default() });
+
+        list.reset_stats();
+        list.push_back(6);
+        list.pop_front();
+        assert_eq!(list.stats(), ListStats { allocations: 1, frees: 1, ..ListStats::default() });
+
+        list.reset_stats();
+        assert_eq!(list.get_clamped(1), Some(&2));
+        assert_eq!(list.get_clamped(4), Some(&5));
+        assert_eq!(list.get_clamped(9), Some(&6));
+        assert_eq!(list.stats().traversal_steps, 2);
+
+        list.reset_stats();
+        let handle = list.back_handle().unwrap();
+        list.move_to_front(&handle);
+        list.cursor_front_mut().splice_after(list_of(&[7, 8]));
+        list.extend_list(LinkedList::new());
+        assert_eq!(list.stats(), ListStats { relinks: 2, ..ListStats::default() });
+
+        list.reset_stats();
+        list.clear();
+        assert_eq!(list.stats(), ListStats { frees: 8, ..ListStats::default() });
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_drain_expired() {
+        let mut timers: LinkedList<i32> = list_of(&[1, 3, 3, 5, 8, 13]);
+        let handle = timers.back_handle().unwrap();
+
+        let ticks: [(i32, &[i32]); 5] = [(0, &[]), (3, &[1, 3, 3]), (4, &[]), (13, &[5, 8, 13]), (20, &[])];
+        for (now, expected) in ticks {
+            let mut drained = timers.drain_expired(now, |deadline| *deadline);
+            assert_links(&drained);
+            assert_links(&timers);
+            if now == 13 {
+                assert_eq!(drained.remove(&handle), Some(13));
+                drained.push_back(13);
+            }
+            assert_eq!(drained.len(), expected.len());
+            assert_eq!(drained.into_iter().collect::<Vec<_>>(), expected);
+        }
+        assert!(timers.is_empty());
+
+        let mut timers: UncountedList<i32> = list_of(&[2, 4, 9]);
+        let drained = timers.drain_expired(4, |deadline| *deadline);
+        assert_links(&drained);
+        assert_eq!(drained.into_iter().collect::<Vec<_>>(), vec![2, 4]);
+        assert_eq!(timers.into_iter().collect::<Vec<_>>(), vec![9]);
+    }
+
+    #[test]
+    fn test_copy_into() {
+        let mut buf = Vec::with_capacity(8);
+        let ptr = buf.as_ptr();
+        let list: LinkedList<i32> = list_of(&[1, 2, 3, 4, 5]);
+        assert_eq!(list.copy_into(&mut buf), &[1, 2, 3, 4, 5]);
+        assert_eq!(LinkedList::<i32>::new().copy_into(&mut buf), &[] as &[i32]);
+        assert_eq!(list_of::<false>(&[7, 8]).copy_into(&mut buf), &[7, 8]);
+        assert_eq!((buf.capacity(), buf.as_ptr()), (8, ptr));
+
+        let mut slots = [0; 3];
+        assert_eq!(list.copy_into_slice(&mut slots), 3);
+        assert_eq!(slots, [1, 2, 3]);
+        let mut slots = [0; 7];
+        assert_eq!(list.copy_into_slice(&mut slots), 5);
+        assert_eq!(slots, [1, 2, 3, 4, 5, 0, 0]);
+
+        assert_links(&list);
+    }
+
+    /// Runs `f` on a thread with a timeout, returning whether it panicked.
+    ///
+    /// A traversal that loops forever fails the test instead of hanging it.
+    fn panics_within_timeout(f: fn()) -> bool {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let result = std::panic::catch_unwind(f);
+            let _ = sender.send(result.is_err());
+        });
+        receiver.recv_timeout(std::time::Duration::from_secs(10)).expect("traversal did not terminate")
+    }
+
+    /// Builds a three-element list whose tail links back to its head.
+    fn corrupted_list() -> LinkedList<i32> {
+        let list = list_of(&[1, 2, 3]);
+        let tail = list.tail.as_ref().and_then(|weak| weak.upgrade()).unwrap();
+        *tail.next.borrow_mut() = list.head.clone();
+        list
+    }
+
+    #[test]
+    fn test_cycle_detected_by_traversals() {
+        let traversals: [fn(); 5] = [
+            || {
+                corrupted_list().contains(&9);
+            },
+            || {
+                corrupted_list().count_of(&1);
+            },
+            || {
+                let _ = format!("{:#?}", corrupted_list());
+            },
+            || corrupted_list().map_in_place(|elem| *elem += 1),
+            || {
+                corrupted_list().copy_into(&mut Vec::new());
+            },
+        ];
+        for traversal in traversals {
+            assert!(panics_within_timeout(traversal));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "list structure corrupted (cycle detected)")]
+    fn test_cycle_detected_message() {
+        corrupted_list().contains(&9);
+    }
+
+    #[test]
+    fn test_keep_last_n() {
+        let drops = Rc::new(std::cell::Cell::new(0));
+        let mut history = LinkedList::new();
+        let mut next = 0;
+        for round in 0..5 {
+            for _ in 0..4 {
+                history.push_back(DropCounter { value: next, drops: drops.clone() });
+                next += 1;
+            }
+            history.keep_last_n(3);
+            assert_links(&history);
+            assert_eq!(drops.get(), 1 + round * 4);
+            assert_eq!(history.len(), 3);
+            assert!(history.nodes().map(|node| node.data.borrow().value).eq(next - 3..next));
+        }
+
+        history.keep_last_n(10);
+        assert_eq!(history.len(), 3);
+        history.keep_last_n(0);
+        assert_links(&history);
+        assert!(history.is_empty());
+        assert_eq!(drops.get(), next as usize);
+
+        let mut list: UncountedList<i32> = list_of(&[1, 2, 3, 4]);
+        list.keep_last_n(2);
+        assert_links(&list);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let list: LinkedList<i32> = list_of(&[1, 2, 3, 4]);
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 2);
+        let rest = iter.clone();
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!((iter.next(), iter.next_back()), (None, None));
+        assert_eq!(rest.collect::<Vec<_>>(), vec![&2, &3]);
+
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+        assert_eq!(LinkedList::<i32>::new().iter().next(), None);
+
+        let uncounted: UncountedList<i32> = list_of(&[1, 2, 3]);
+        assert_eq!(uncounted.iter().size_hint(), (1, None));
+        assert_eq!(uncounted.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(uncounted.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_iter_detects_cycle() {
+        let list = corrupted_list();
+        let head = list.head.clone().unwrap();
+        *head.prev.borrow_mut() = list.tail.clone();
+        let mut backward = list.iter();
+        backward.front = None;
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| backward.rev().count())).is_err());
+
+        assert_eq!(list.validate(), Err(ConsistencyError::BrokenPrevLink { index: 0 }));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(LinkedList::<i32>::new().validate(), Ok(()));
+        assert_eq!(list_of::<false>(&[1, 2, 3]).assert_consistent(), Ok(()));
+        assert_eq!(corrupted_list().validate(), Err(ConsistencyError::BrokenPrevLink { index: 3 }));
+
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        list.length = 5;
+        assert_eq!(list.validate(), Err(ConsistencyError::LengthMismatch { expected: 5, actual: 3 }));
+        list.length = 3;
+
+        let middle = list.node_at(1).map(|node| node.next.borrow().clone()).unwrap();
+        list.tail = middle.as_ref().map(|node| node.prev.borrow().clone().unwrap());
+        assert_eq!(list.assert_consistent(), Err(ConsistencyError::BrokenTail));
+        assert_eq!(ConsistencyError::BrokenTail.to_string(), "the tail is not the last node");
+    }
+
+    /// Builds a list of drop-counting elements holding `0..len`.
+    fn counter_list(len: i32, drops: &Rc<std::cell::Cell<usize>>) -> LinkedList<DropCounter> {
+        let mut list = LinkedList::new();
+        for value in 0..len {
+            list.push_back(DropCounter { value, drops: drops.clone() });
+        }
+        list
+    }
+
+    #[test]
+    fn test_map_in_place_unwind() {
+        for panic_at in [0, 2, 4] {
+            let drops = Rc::new(std::cell::Cell::new(0));
+            let mut list = counter_list(5, &drops);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.map_in_place(|elem| {
+                    assert!(elem.value != panic_at, "boom");
+                    elem.value += 10;
+                });
+            }));
+
+            assert!(result.is_err());
+            assert_links(&list);
+            let expected: Vec<_> = (0..5).map(|value| if value < panic_at { value + 10 } else { value }).collect();
+            assert_eq!(list.iter().map(|elem| elem.value).collect::<Vec<_>>(), expected);
+            drop(list);
+            assert_eq!(drops.get(), 5);
+        }
+    }
+
+    #[test]
+    fn test_drain_expired_unwind() {
+        for panic_at in [0, 2, 4] {
+            let drops = Rc::new(std::cell::Cell::new(0));
+            let mut list = counter_list(5, &drops);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.drain_expired(10, |elem| {
+                    assert!(elem.value != panic_at, "boom");
+                    elem.value
+                })
+            }));
+
+            assert!(result.is_err());
+            assert_links(&list);
+            assert_eq!(list.len(), 5);
+            assert_eq!(drops.get(), 0);
+            drop(list);
+            assert_eq!(drops.get(), 5);
+        }
+    }
+
+    #[test]
+    fn test_try_map_unwind() {
+        for panic_at in [0, 2, 4] {
+            let drops = Rc::new(std::cell::Cell::new(0));
+            let list = counter_list(5, &drops);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.try_map(|elem| {
+                    assert!(elem.value != panic_at, "boom");
+                    Ok::<_, ()>(elem)
+                })
+            }));
+
+            assert!(result.is_err());
+            assert_eq!(drops.get(), 5);
+        }
+    }
+
+    #[test]
+    fn test_partition_point() {
+        fn check<const C: bool>(values: &[i32], pred: fn(&i32) -> bool, expected: usize) {
+            let list = list_of::<C>(values);
+            assert_eq!(values.partition_point(pred), expected);
+            assert_eq!(list.partition_point(pred), expected);
+            assert_eq!(list.rpartition_point(pred), expected);
+        }
+
+        for (values, pred, expected) in [
+            (&[1, 2, 3, 3, 5, 6, 7][..], (|x: &i32| *x < 5) as fn(&i32) -> bool, 4),
+            (&[1, 2, 3, 3, 5, 6, 7][..], |x| *x < 3, 2),
+            (&[1, 2, 3][..], |x| *x < 100, 3),
+            (&[1, 2, 3][..], |x| *x < 0, 0),
+            (&[][..], |x| *x < 5, 0),
+            (&[4][..], |x| *x < 5, 1),
+        ] {
+            check::<true>(values, pred, expected);
+            check::<false>(values, pred, expected);
+        }
+    }
+
+    #[test]
+    fn test_make_circular_and_break_cycle() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        list.make_circular();
+        assert!(list.is_circular());
+        assert_links(&list);
+        let tail = list.tail.as_ref().and_then(|weak| weak.upgrade()).unwrap();
+        assert!(Rc::ptr_eq(tail.next.borrow().as_ref().unwrap(), list.head.as_ref().unwrap()));
+        drop(tail);
+
+        assert_eq!(list.iter().count(), 3);
+        assert_eq!(format!("{:?}", list), "1 <-> 2 <-> 3 <-> End");
+        assert_eq!(list.strong_count_front(), Some(1));
+        assert_eq!(list.get_clamped(5), Some(&3));
+
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_next();
+        assert_eq!(cursor.current().as_deref(), Some(&1));
+        cursor.move_prev();
+        assert_eq!(cursor.current().as_deref(), Some(&3));
+        cursor.splice_after(list_of(&[4]));
+        cursor.splice_before(LinkedList::new());
+        assert_links(&list);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+        list.break_cycle();
+        assert!(!list.is_circular());
+        assert_links(&list);
+        let tail = list.tail.as_ref().and_then(|weak| weak.upgrade()).unwrap();
+        assert!(tail.next.borrow().is_none());
+        assert!(list.head.as_ref().unwrap().prev.borrow().is_none());
+    }
+
+    #[test]
+    fn test_ring_operations() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.make_circular();
+        list.push_back(1);
+        assert_eq!(list.strong_count_back(), Some(1));
+        assert_links(&list);
+        list.push_front(0);
+        list.push_back(2);
+        list.extend_list(list_of(&[3, 4]));
+        let mut ring = list_of(&[5, 6]);
+        ring.make_circular();
+        list.extend_list(ring);
+        assert_links(&list);
+
+        let handle = list.back_handle().unwrap();
+        assert!(list.move_to_front(&handle));
+        assert_links(&list);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [6, 0, 1, 2, 3, 4, 5]);
+        assert_eq!(list.try_pop_front(), Ok(6));
+        assert_eq!(list.pop_back(), Some(5));
+
+        let expired = list.drain_expired(1, |x| *x);
+        assert!(!expired.is_circular());
+        assert_links(&expired);
+        assert_eq!(expired.into_iter().collect::<Vec<_>>(), [0, 1]);
+        list.keep_last_n(2);
+        assert_links(&list);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 4]);
+
+        list.clear();
+        assert!(list.is_circular());
+        list.push_back(7);
+        assert_links(&list);
+        assert_eq!(list.pop_front(), Some(7));
+        assert_eq!(list.pop_front(), None);
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_rotate() {
+        fn check<const C: bool>(circular: bool) {
+            let mut list = list_of::<C>(&[1, 2, 3, 4, 5]);
+            if circular {
+                list.make_circular();
+            }
+            let handle = list.front_handle().unwrap();
+
+            list.rotate(2);
+            assert_links(&list);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 4, 5, 1, 2]);
+            assert_eq!(list.is_circular(), circular);
+            list.rotate(3);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+            list.rotate(12);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 4, 5, 1, 2]);
+            assert_eq!(handle.get(), Some(1));
+            assert_links(&list);
+
+            let mut empty = list_of::<C>(&[]);
+            empty.rotate(3);
+            assert!(empty.is_empty());
+        }
+
+        check::<true>(false);
+        check::<true>(true);
+        check::<false>(false);
+        check::<false>(true);
+    }
+
+    #[test]
+    fn test_ring_drops_every_node() {
+        for circular in [false, true] {
+            let drops = Rc::new(std::cell::Cell::new(0));
+            let mut list = counter_list(5, &drops);
+            if circular {
+                list.make_circular();
+            }
+            list.rotate(2);
+            drop(list);
+            assert_eq!(drops.get(), 5);
+
+            let mut list = counter_list(3, &drops);
+            list.make_circular();
+            list.clear();
+            assert_eq!(drops.get(), 8);
+            list.push_back(DropCounter { value: 0, drops: drops.clone() });
+            let mut iter = list.into_iter();
+            assert_eq!(iter.next().map(|elem| elem.value), Some(0));
+            assert_eq!(drops.get(), 9);
+        }
+    }
+
+    #[test]
+    fn test_validate_ring() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        list.make_circular();
+        let tail = list.tail.as_ref().and_then(|weak| weak.upgrade()).unwrap();
+        *tail.next.borrow_mut() = None;
+        assert_eq!(list.validate(), Err(ConsistencyError::BrokenRing));
+    }
+
+    #[test]
+    fn test_sort_and_reverse() {
+        let mut rng = crate::testing::XorShift::new(0x50e7);
+        for len in [0, 1, 2, 7, 40] {
+            let values: Vec<i32> = (0..len).map(|_| rng.below(10) as i32).collect();
+            let mut list: LinkedList<(i32, usize)> = LinkedList::new();
+            for (index, value) in values.iter().enumerate() {
+                list.push_back((*value, index));
+            }
+
+            let mut expected: Vec<_> = list.iter().copied().collect();
+            expected.sort_by_key(|(value, _)| *value);
+            list.sort_by(|a, b| a.0.cmp(&b.0));
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            assert_links(&list);
+
+            expected.reverse();
+            list.reverse();
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            assert_links(&list);
+        }
+
+        let mut ring: LinkedList<i32> = list_of(&[3, 1, 2]);
+        ring.make_circular();
+        ring.sort();
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        ring.reverse();
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [3, 2, 1]);
+        assert_links(&ring);
+    }
+
+    #[test]
+    fn test_splice_at() {
+        for (index, expected) in [(0, [7, 8, 1, 2, 3]), (1, [1, 7, 8, 2, 3]), (3, [1, 2, 3, 7, 8])] {
+            let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+            list.splice_at(index, list_of(&[7, 8]));
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            assert_links(&list);
+        }
+
+        let mut list: LinkedList<i32> = list_of(&[1, 2]);
+        list.splice_at(1, LinkedList::new());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2]);
+        assert_links(&list);
+    }
+
+    #[test]
+    #[should_panic(expected = "splice_at: index 3 out of range for list of length 2")]
+    fn test_splice_at_out_of_range() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2]);
+        list.splice_at(3, LinkedList::new());
+    }
+
+    #[test]
+    fn test_handles_survive_structural_operations() {
+        let mut list: LinkedList<i32> = list_of(&[5, 3, 9, 1]);
+        let mut other: LinkedList<i32> = list_of(&[4]);
+        let spliced = other.front_handle().unwrap();
+        let handles: Vec<_> = [list.front_handle(), list.back_handle()].into_iter().flatten().collect();
+        let check = |list: &LinkedList<i32>| {
+            assert_eq!(handles[0].get(), Some(5));
+            assert_eq!(handles[1].get(), Some(1));
+            assert_links(list);
+        };
+
+        list.sort();
+        check(&list);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 3, 5, 9]);
+        list.reverse();
+        check(&list);
+        list.rotate(3);
+        check(&list);
+        list.splice_at(2, std::mem::take(&mut other));
+        check(&list);
+        assert_eq!(spliced.get(), Some(4));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 9, 4, 5, 3]);
+
+        assert!(list.move_to_front(&handles[0]));
+        assert!(list.move_to_front(&spliced));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [4, 5, 1, 9, 3]);
+        assert_eq!(list.remove(&handles[1]), Some(1));
+        assert!(!handles[1].is_valid());
+        assert!(handles[0].is_valid());
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_retain_with() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        let mut index = std::collections::HashMap::new();
+        for key in 0..10 {
+            list.push_back(key);
+            index.insert(key, list.back_handle().unwrap());
+        }
+
+        let mut removed = Vec::new();
+        list.retain_with(
+            |key| key % 2 == 0,
+            |handle, key| {
+                assert!(!handle.is_valid());
+                assert!(handle.node.ptr_eq(&index[&key].node));
+                removed.push(key);
+            },
+        );
+        assert_eq!(removed, [1, 3, 5, 7, 9]);
+        for key in removed {
+            index.remove(&key);
+        }
+        assert_links(&list);
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+        for (key, handle) in &index {
+            assert_eq!(handle.get(), Some(*key));
+        }
+        assert!(list.move_to_front(&index[&6]));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [6, 0, 2, 4, 8]);
+
+        let mut ring: LinkedList<i32> = list_of(&[1, 2, 3]);
+        ring.make_circular();
+        ring.retain_with(|x| *x == 2, |_, _| {});
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [2]);
+        assert_links(&ring);
+    }
+
+    #[test]
+    fn test_fsck_clean_list() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        assert!(list.fsck().is_clean());
+        assert!(LinkedList::<i32>::new().fsck().is_clean());
+
+        list.make_circular();
+        assert!(list.fsck().is_clean());
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_fsck_repairs_links() {
+        let mut list = corrupted_list();
+        let report = list.fsck();
+        assert_eq!(report, FsckReport { next_fixed: 1, ..FsckReport::default() });
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_links(&list);
+
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3, 4]);
+        let handle = list.back_handle().unwrap();
+        for node in list.node_refs() {
+            *node.prev.borrow_mut() = None;
+        }
+        list.tail = list.head.as_ref().map(Rc::downgrade);
+        list.length = 7;
+        let report = list.fsck();
+        assert_eq!(report, FsckReport { prev_fixed: 3, next_fixed: 0, length_delta: -3, tail_fixed: true });
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [4, 3, 2, 1]);
+        assert_eq!(handle.get(), Some(4));
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_fsck_repairs_ring() {
+        let mut list: UncountedList<i32> = list_of(&[1, 2, 3]);
+        list.make_circular();
+        let tail = list.tail.as_ref().and_then(Weak::upgrade).unwrap();
+        *tail.next.borrow_mut() = None;
+        *list.head.as_ref().unwrap().prev.borrow_mut() = None;
+        drop(tail);
+        assert_eq!(list.validate(), Err(ConsistencyError::BrokenRing));
+
+        let report = list.fsck();
+        assert_eq!(report, FsckReport { prev_fixed: 1, next_fixed: 1, length_delta: 0, tail_fixed: false });
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_scan_in_place() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3, 4]);
+        let total = list.scan_in_place(0, |sum, x| {
+            *sum += *x;
+            *x = *sum;
+        });
+        assert_eq!(total, 10);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 3, 6, 10]);
+
+        let mut list: UncountedList<i32> = list_of(&[1, 2, 3, 4]);
+        list.make_circular();
+        let total = list.rscan_in_place(0, |sum, x| {
+            *sum += *x;
+            *x = *sum;
+        });
+        assert_eq!(total, 10);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [10, 9, 7, 4]);
+        assert_links(&list);
+
+        let mut empty = LinkedList::<i32>::new();
+        assert_eq!(empty.rscan_in_place(5, |_, _| unreachable!()), 5);
+    }
+
+    #[cfg(feature = "observer")]
+    #[test]
+    fn test_observer_reports_mutations() {
+        let log: Rc<RefCell<Vec<ListEvent<i32>>>> = Rc::default();
+        let mut list: LinkedList<i32> = list_of(&[1]);
+        let sink = log.clone();
+        list.set_observer(move |event| sink.borrow_mut().push(event.cloned()));
+
+        list.push_front(0);
+        list.push_back(2);
+        list.push_back(3);
+        let handle = list.back_handle().unwrap();
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.remove_first(&2), Some(2));
+        assert_eq!(list.remove(&handle), Some(3));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+        list.push_front(4);
+        list.clear();
+
+        assert_eq!(
+            *RefCell::borrow(&log),
+            [
+                ListEvent::PushedFront(0),
+                ListEvent::PushedBack(2),
+                ListEvent::PushedBack(3),
+                ListEvent::PoppedFront(0),
+                ListEvent::Removed { index: 1, elem: 2 },
+                ListEvent::Removed { index: 1, elem: 3 },
+                ListEvent::PoppedBack(1),
+                ListEvent::PushedFront(4),
+                ListEvent::Cleared,
+            ]
+        );
+
+        assert!(list.remove_observer());
+        list.push_back(5);
+        assert_eq!(RefCell::borrow(&log).len(), 9);
+        assert_links(&list);
+    }
+
+    #[cfg(feature = "observer")]
+    #[test]
+    fn test_observer_cannot_reenter() {
+        let list = Rc::new(RefCell::new(LinkedList::<i32>::new()));
+        let weak = Rc::downgrade(&list);
+        list.borrow_mut().set_observer(move |_| {
+            if let Some(list) = weak.upgrade() {
+                list.borrow_mut().push_back(0);
+            }
+        });
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.borrow_mut().push_back(1)));
+        assert!(result.is_err());
+    }
+
+    fn edit_script<const TRACK_LEN: bool>(list: &mut LinkedList<i32, TRACK_LEN>) -> Vec<i32> {
+        let mut visited = Vec::new();
+        list.edit_spans(|editor| {
+            let value = *editor.current().unwrap();
+            visited.push(value);
+            match value {
+                3 => {
+                    assert_eq!(editor.replace_current(30), 3);
+                    editor.insert_after(31);
+                    editor.insert_after(32);
+                }
+                5 => editor.skip(1),
+                7 => {
+                    assert_eq!(editor.remove_current(), Some(7));
+                    assert_eq!(editor.remove_current(), None);
+                    editor.insert_after(70);
+                }
+                _ if value % 4 == 0 => {
+                    editor.remove_current();
+                }
+                _ => {}
+            }
+        });
+        visited
+    }
+
+    #[test]
+    fn test_edit_spans() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let handle = list.front_handle().unwrap();
+        assert_eq!(edit_script(&mut list), [1, 2, 3, 4, 5, 7, 8]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 30, 31, 32, 5, 6, 70]);
+        assert_eq!(list.len(), 8);
+        assert_eq!(handle.get(), Some(1));
+        assert_links(&list);
+
+        let mut ring: UncountedList<i32> = list_of(&[8, 3, 7]);
+        ring.make_circular();
+        assert_eq!(edit_script(&mut ring), [8, 3, 7]);
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [30, 31, 32, 70]);
+        assert_links(&ring);
+    }
+
+    #[test]
+    fn test_edit_spans_replaces_removed_front() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        list.edit_spans(|editor| {
+            let value = editor.remove_current().unwrap();
+            if value != 2 {
+                editor.insert_after(value * 10);
+                editor.insert_after(value * 10 + 1);
+            }
+        });
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [10, 11, 30, 31]);
+        assert_links(&list);
+
+        list.edit_spans(|editor| {
+            editor.remove_current();
+        });
+        assert!(list.is_empty());
+        assert_links(&list);
+
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        let mut visits = 0;
+        list.edit_spans(|editor| {
+            visits += 1;
+            editor.skip(usize::MAX);
+        });
+        assert_eq!(visits, 1);
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let mut list: LinkedList<(i32, char)> = LinkedList::new();
+        for item in [(1, 'a'), (3, 'a'), (3, 'b'), (7, 'a')] {
+            list.push_back(item);
+        }
+        let mut other = LinkedList::new();
+        for item in [(0, 'x'), (3, 'x'), (8, 'x')] {
+            other.push_back(item);
+        }
+        let handle = other.back_handle().unwrap();
+
+        list.merge_sorted(other);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            [(0, 'x'), (1, 'a'), (3, 'a'), (3, 'b'), (3, 'x'), (7, 'a'), (8, 'x')]
+        );
+        assert_eq!(list.len(), 7);
+        assert_eq!(handle.get(), Some((8, 'x')));
+        assert_links(&list);
+
+        let mut empty = LinkedList::new();
+        empty.merge_sorted(list_of::<true>(&[1, 2]));
+        assert_eq!(empty.iter().copied().collect::<Vec<_>>(), [1, 2]);
+        empty.merge_sorted(LinkedList::new());
+        assert_links(&empty);
+    }
+
+    #[test]
+    fn test_iter_with_neighbors() {
+        let list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        let mut triples = list.iter_with_neighbors();
+        assert_eq!(triples.size_hint(), (3, Some(3)));
+        assert_eq!(triples.next(), Some((None, &1, Some(&2))));
+        assert_eq!(triples.len(), 2);
+        assert_eq!(triples.collect::<Vec<_>>(), [(Some(&1), &2, Some(&3)), (Some(&2), &3, None)]);
+
+        let mut ring: UncountedList<i32> = list_of(&[4]);
+        ring.make_circular();
+        assert_eq!(ring.iter_with_neighbors().collect::<Vec<_>>(), [(None, &4, None)]);
+
+        let empty = LinkedList::<i32>::new();
+        assert_eq!(empty.iter_with_neighbors().size_hint(), (0, Some(0)));
+        assert_eq!(empty.iter_with_neighbors().next(), None);
+    }
+
+    /// Fails to compile if `ListBuilder<T>` stops being `Send` for every `T: Send`.
+    #[allow(dead_code)]
+    fn assert_builder_is_send<T: Send>() {
+        fn assert_send<S: Send>() {}
+        assert_send::<ListBuilder<T>>();
+    }
+
+    #[test]
+    fn test_list_builder_across_threads() {
+        let builder = std::thread::spawn(|| {
+            let mut builder = ListBuilder::new();
+            for i in 0..100 {
+                builder.push_back(i.to_string());
+            }
+            builder
+        })
+        .join()
+        .unwrap();
+        assert_eq!(builder.len(), 100);
+
+        let list = builder.build();
+        assert_eq!(list.len(), 100);
+        assert!(list.iter().cloned().eq((0..100).map(|i| i.to_string())));
+        assert_links(&list);
+
+        let empty = ListBuilder::<i32>::default();
+        assert!(empty.is_empty());
+        assert!(empty.build().is_empty());
+    }
+
+    #[test]
+    fn test_swap_remove_back() {
+        let mut list = list_of::<true>(&[0, 1, 2, 3, 4, 5]);
+        let back = list.back_handle().unwrap();
+        assert_eq!(list.swap_remove_back(6), None);
+
+        assert_eq!(list.swap_remove_back(0), Some(0));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 1, 2, 3, 4]);
+        assert_eq!(back.get(), Some(5));
+        assert_links(&list);
+
+        assert_eq!(list.swap_remove_back(3), Some(3));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 1, 2, 4]);
+        assert_eq!(list.swap_remove_back(1), Some(1));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 4, 2]);
+        assert_eq!(list.swap_remove_back(2), Some(2));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 4]);
+        assert_links(&list);
+
+        let mut only = list_of::<false>(&[7]);
+        assert_eq!(only.swap_remove_back(0), Some(7));
+        assert!(only.is_empty());
+        assert_eq!(only.swap_remove_back(0), None);
+        assert_links(&only);
+
+        let mut ring = list_of::<true>(&[0, 1, 2, 3]);
+        ring.make_circular();
+        assert_eq!(ring.swap_remove_back(2), Some(2));
+        assert_eq!(ring.swap_remove_back(0), Some(0));
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [3, 1]);
+        assert_eq!(ring.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_split_off_after_and_before() {
+        let mut list = LinkedList::new();
+        let mut handles = Vec::new();
+        for i in (0..5).rev() {
+            list.push_front(i);
+            handles.push(list.front_handle().unwrap());
+        }
+        handles.reverse();
+
+        let mut tail = list.split_off_after(&handles[0]).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0]);
+        assert_eq!(tail.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!((list.len(), tail.len()), (1, 4));
+        assert_links(&list);
+        assert_links(&tail);
+
+        let front = tail.split_off_before(&handles[4]).unwrap();
+        assert_eq!(front.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(tail.iter().copied().collect::<Vec<_>>(), [4]);
+        assert_links(&front);
+        assert_links(&tail);
+
+        let mut front = front;
+        assert_eq!(front.remove(&handles[2]), Some(2));
+        assert_eq!(tail.remove(&handles[4]), Some(4));
+        assert!(!handles[4].is_valid());
+        assert!(front.split_off_after(&handles[4]).is_none());
+        assert!(handles[1].is_valid() && handles[3].is_valid());
+
+        let only = list.front_handle().unwrap();
+        assert!(list.split_off_after(&only).unwrap().is_empty());
+        assert!(list.split_off_before(&only).unwrap().is_empty());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0]);
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_split_off_uncounted_and_ring() {
+        let mut list = list_of::<false>(&[0, 1, 2, 3]);
+        let back = list.back_handle().unwrap();
+        let front = list.split_off_before(&back).unwrap();
+        assert_eq!(front.iter().copied().collect::<Vec<_>>(), [0, 1, 2]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3]);
+        assert_links(&front);
+        assert_links(&list);
+
+        let mut ring = list_of::<true>(&[0, 1, 2, 3]);
+        ring.make_circular();
+        let head = ring.front_handle().unwrap();
+        let rest = ring.split_off_after(&head).unwrap();
+        assert!(ring.is_circular() && !rest.is_circular());
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [0]);
+        assert_eq!(rest.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(ring.validate(), Ok(()));
+        assert_eq!(rest.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_reordering_keeps_element_addresses() {
+        let mut list = list_of::<true>(&[5, 3, 8, 1, 9, 2]);
+        let addresses = |list: &LinkedList<i32>| {
+            let mut addresses: Vec<(i32, *const i32)> = list.iter().map(|elem| (*elem, elem as *const i32)).collect();
+            addresses.sort();
+            addresses
+        };
+        let before = addresses(&list);
+
+        list.sort();
+        assert_eq!(addresses(&list), before);
+        list.reverse();
+        assert_eq!(addresses(&list), before);
+        list.rotate(4);
+        assert_eq!(addresses(&list), before);
+        list.make_circular();
+        list.rotate(3);
+        list.break_cycle();
+        assert_eq!(addresses(&list), before);
+        let back = list.back_handle().unwrap();
+        list.move_to_front(&back);
+        assert_eq!(addresses(&list), before);
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_cursor_peek_two_and_consume_if() {
+        let mut list = list_of::<true>(&[1, 2, 3]);
+        let handle = list.back_handle().unwrap();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.peek_two(), (Some(&1), Some(&2)));
+        assert_eq!(cursor.consume_if(|&x| x > 1), None);
+        assert_eq!(cursor.consume_if(|&x| x == 1), Some(1));
+        assert_eq!(cursor.peek_two(), (Some(&2), Some(&3)));
+        cursor.move_next();
+        assert_eq!(cursor.peek_two(), (Some(&3), None));
+        assert_eq!(cursor.consume_if(|_| true), Some(3));
+        assert_eq!(cursor.peek_two(), (None, Some(&2)));
+        assert_eq!(cursor.consume_if(|_| true), None);
+        assert!(!handle.is_valid());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [2]);
+        assert_links(&list);
+
+        let mut ring = list_of::<false>(&[1, 2]);
+        ring.make_circular();
+        let mut cursor = ring.cursor_back_mut();
+        assert_eq!(cursor.peek_two(), (Some(&2), Some(&1)));
+        assert_eq!(cursor.consume_if(|_| true), Some(2));
+        assert_eq!(cursor.peek_two(), (Some(&1), Some(&1)));
+        assert_eq!(cursor.consume_if(|_| true), Some(1));
+        assert_eq!(cursor.peek_two(), (None, None));
+        assert!(ring.is_empty());
+        assert_eq!(ring.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_transfer_range() {
+        let cases: [(std::ops::Range<usize>, usize); 6] = [(0..2, 0), (1..4, 2), (3..5, 3), (0..5, 1), (4..5, 0), (2..2, 1)];
+        for (range, at) in cases {
+            let mut src = list_of::<true>(&[0, 1, 2, 3, 4]);
+            let mut dst = list_of::<true>(&[10, 11, 12]);
+            let mut expected_src: Vec<i32> = vec![0, 1, 2, 3, 4];
+            let mut expected_dst: Vec<i32> = vec![10, 11, 12];
+            let moved: Vec<i32> = expected_src.drain(range.clone()).collect();
+            expected_dst.splice(at..at, moved);
+
+            dst.transfer_range(&mut src, range.clone(), at);
+            assert_eq!(src.iter().copied().collect::<Vec<_>>(), expected_src, "{:?} at {}", range, at);
+            assert_eq!(dst.iter().copied().collect::<Vec<_>>(), expected_dst, "{:?} at {}", range, at);
+            assert_links(&src);
+            assert_links(&dst);
+        }
+    }
+
+    #[test]
+    fn test_transfer_range_keeps_handles() {
+        let mut src = list_of::<false>(&[1, 2]);
+        src.make_circular();
+        let back = src.back_handle().unwrap();
+        let mut dst = list_of::<false>(&[]);
+        dst.transfer_range(&mut src, 1..2, 0);
+        assert_eq!(dst.remove(&back), Some(2));
+        assert_eq!(src.iter().copied().collect::<Vec<_>>(), [1]);
+        assert_eq!(src.validate(), Ok(()));
+        assert!(dst.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "transfer_range: range 1..4 out of range for list of length 3")]
+    fn test_transfer_range_out_of_bounds() {
+        let mut src = list_of::<true>(&[1, 2, 3]);
+        let mut dst = list_of::<true>(&[]);
+        dst.transfer_range(&mut src, 1..4, 0);
+    }
+
+    #[test]
+    fn test_front_back_and_iter_mut() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        assert_eq!((list.front(), list.back()), (Some(&1), Some(&3)));
+        *list.front_mut().unwrap() = 10;
+        *list.back_mut().unwrap() = 30;
+        for mut elem in list.iter_mut().rev().skip(1) {
+            *elem += 1;
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [11, 3, 30]);
+
+        let mut items: Vec<_> = (&mut list).into_iter().collect();
+        *items[2] = 0;
+        drop(items);
+        assert_eq!(list.back(), Some(&0));
+
+        let mut ring: UncountedList<i32> = list_of(&[1, 2]);
+        ring.make_circular();
+        assert_eq!(ring.back(), Some(&2));
+        assert_eq!(ring.iter_mut().count(), 2);
+        assert_links(&ring);
+    }
+
+    #[test]
+    fn test_append_and_split_off() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2]);
+        let mut other = list_of(&[3, 4, 5]);
+        let handle = other.back_handle().unwrap();
+        list.append(&mut other);
+        assert!(other.is_empty());
+        assert_links(&other);
+        assert_eq!(list.len(), 5);
+
+        let back = list.split_off(3);
+        assert_eq!(back.iter().copied().collect::<Vec<_>>(), [4, 5]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(handle.get(), Some(5));
+        assert_links(&list);
+        assert_links(&back);
+        assert!(list.split_off(3).is_empty());
+
+        let mut uncounted: UncountedList<i32> = list_of(&[1, 2, 3]);
+        let all = uncounted.split_off(0);
+        assert!(uncounted.is_empty());
+        assert_eq!(all.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "split_off: index 4 out of range for list of length 3")]
+    fn test_split_off_out_of_range() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        list.split_off(4);
+    }
+
+    #[test]
+    fn test_comparison_traits() {
+        let list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        let copy = list.clone();
+        assert_eq!(list, copy);
+        assert_links(&copy);
+        assert!(list < list_of(&[1, 3]));
+        assert!(list > list_of(&[1, 2]));
+        assert_ne!(list, list_of(&[1, 2]));
+
+        let mut ring: UncountedList<i32> = [1, 2].into_iter().collect();
+        ring.make_circular();
+        let ring_copy = ring.clone();
+        assert!(ring_copy.is_circular());
+        assert_eq!(ring, ring_copy);
+
+        let mut extended: LinkedList<i32> = LinkedList::new();
+        extended.extend(&[1, 2]);
+        extended.extend(Some(3));
+        assert_eq!(extended, list);
+        assert_eq!(extended.cmp(&list), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_shared_node_count_and_pop_front_shared() {
+        let mut list: LinkedList<i32> = list_of(&[1, 2, 3]);
+        assert_eq!(list.shared_node_count(), 0);
+
+        let front = list.head.clone().unwrap();
+        let middle = list.nodes().nth(1).unwrap();
+        assert_eq!(list.shared_node_count(), 2);
+        drop(middle);
+        assert_eq!(list.shared_node_count(), 1);
+
+        let handle = list.front_handle().unwrap();
+        assert_eq!(list.pop_front_shared(), Some(PopOutcome::SharedDropped));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.shared_node_count(), 0);
+        assert_links(&list);
+        assert_eq!(*front.data(), 1);
+        assert!(handle.is_valid());
+        drop(front);
+        assert!(!handle.is_valid());
+
+        assert_eq!(list.pop_front_shared(), Some(PopOutcome::Unique(2)));
+        assert_eq!(list.pop_front_shared(), Some(PopOutcome::Unique(3)));
+        assert_eq!(list.pop_front_shared(), None);
+        assert_links(&list);
+    }
+
+    #[test]
+    fn test_shared_node_count_ring() {
+        let mut ring: LinkedList<i32> = list_of(&[1, 2]);
+        ring.make_circular();
+        assert_eq!(ring.shared_node_count(), 0);
+
+        let front = ring.head.clone().unwrap();
+        assert_eq!(ring.shared_node_count(), 1);
+        assert_eq!(ring.pop_front_shared(), Some(PopOutcome::SharedDropped));
+        assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [2]);
+        assert!(ring.is_circular());
+        assert_links(&ring);
+        drop(front);
+        assert_eq!(ring.shared_node_count(), 0);
+    }
+}
@@ -1,5 +1,72 @@
+/// Default number of elements shown by the `Debug` implementations of the lists.
+///
+/// Longer lists are truncated with a `.. N more` marker. Use the precision flag
+/// (`{:.100?}`) to choose a different limit, or the alternate flag (`{:#?}`) to
+/// print every element.
+pub const DEBUG_LIMIT: usize = 32;
+
+/// Errors reported by the consistency checks of the lists.
+///
+/// Returned by [`single::LinkedList::assert_consistent`] and by
+/// [`doubly::LinkedList::validate`], which [`doubly::LinkedList::assert_consistent`]
+/// delegates to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsistencyError {
+    /// The recorded length differs from the number of nodes in the chain.
+    LengthMismatch {
+        /// The length recorded by the list.
+        expected: usize,
+        /// The number of nodes actually linked.
+        actual: usize,
+    },
+    /// The `prev` link of the node at `index` does not point at the node before it.
+    ///
+    /// This includes a `next` link that loops back to an earlier node.
+    BrokenPrevLink {
+        /// The position of the first node with a wrong `prev` link.
+        index: usize,
+    },
+    /// The tail pointer is not the last node of the chain.
+    BrokenTail,
+    /// A list in ring mode whose tail and head are not linked to each other.
+    BrokenRing,
+}
+
+impl std::fmt::Display for ConsistencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsistencyError::LengthMismatch { expected, actual } => {
+                write!(f, "the list records {} elements but links {}", expected, actual)
+            }
+            ConsistencyError::BrokenPrevLink { index } => write!(f, "broken prev link at index {}", index),
+            ConsistencyError::BrokenTail => write!(f, "the tail is not the last node"),
+            ConsistencyError::BrokenRing => write!(f, "the tail and head of the ring are not linked"),
+        }
+    }
+}
+
+impl std::error::Error for ConsistencyError {}
+
 /// Single linked list implementation.
 pub mod single;
 
 /// Doubly linked list implementation.
 pub mod doubly;
+
+/// Algorithms generic over both list types through the `List` trait.
+pub mod algorithms;
+
+/// Fixed-capacity linked list that never allocates.
+pub mod array;
+
+/// Drop-in replacement for `std::collections::LinkedList` backed by the doubly list.
+pub mod compat;
+
+/// Copy-on-write wrappers for sharing a single linked list between readers.
+pub mod shared;
+
+/// Operation counters recorded by the lists with the `stats` feature.
+pub mod stats;
+
+/// Helpers for comparing lists in tests.
+pub mod testing;
@@ -0,0 +1,200 @@
+use std::ops::Deref;
+use std::rc::Rc;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use crate::single::LinkedList;
+
+/// A cheaply cloneable, copy-on-write handle to a singly linked list.
+///
+/// Cloning a `SharedList` only bumps a reference count. The read-only API of the
+/// underlying list is available through `Deref`, and [`SharedList::make_mut`]
+/// produces a private copy only when the list is actually shared.
+pub struct SharedList<T> {
+    inner: Rc<LinkedList<T>>,
+}
+
+impl<T> SharedList<T> {
+    /// Wraps `list` in a new, unshared handle.
+    ///
+    /// # Arguments
+    ///
+    /// * `list` - The list to be shared.
+    pub fn new(list: LinkedList<T>) -> Self {
+        SharedList { inner: Rc::new(list) }
+    }
+
+    /// Returns `true` if both handles point to the same underlying list.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Rc::ptr_eq(&this.inner, &other.inner)
+    }
+
+    /// Returns a mutable reference to the underlying list.
+    ///
+    /// If other handles share the list, it is cloned first so that they are
+    /// left unchanged, like `Rc::make_mut`.
+    pub fn make_mut(&mut self) -> &mut LinkedList<T>
+    where
+        T: Clone,
+    {
+        if Rc::get_mut(&mut self.inner).is_none() {
+            self.inner = Rc::new(LinkedList::clone(&self.inner));
+        }
+
+        match Rc::get_mut(&mut self.inner) {
+            Some(list) => list,
+            None => unreachable!("freshly copied list is shared"),
+        }
+    }
+}
+
+impl<T> Clone for SharedList<T> {
+    fn clone(&self) -> Self {
+        SharedList { inner: Rc::clone(&self.inner) }
+    }
+}
+
+impl<T> Deref for SharedList<T> {
+    type Target = LinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> From<LinkedList<T>> for SharedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        SharedList::new(list)
+    }
+}
+
+/// Thread-safe counterpart of [`SharedList`], backed by `Arc`.
+#[cfg(feature = "std")]
+pub struct SyncSharedList<T> {
+    inner: Arc<LinkedList<T>>,
+}
+
+#[cfg(feature = "std")]
+impl<T> SyncSharedList<T> {
+    /// Wraps `list` in a new, unshared handle.
+    ///
+    /// # Arguments
+    ///
+    /// * `list` - The list to be shared.
+    pub fn new(list: LinkedList<T>) -> Self {
+        SyncSharedList { inner: Arc::new(list) }
+    }
+
+    /// Returns `true` if both handles point to the same underlying list.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.inner, &other.inner)
+    }
+
+    /// Returns a mutable reference to the underlying list.
+    ///
+    /// If other handles share the list, it is cloned first so that they are
+    /// left unchanged, like `Arc::make_mut`.
+    pub fn make_mut(&mut self) -> &mut LinkedList<T>
+    where
+        T: Clone,
+    {
+        if Arc::get_mut(&mut self.inner).is_none() {
+            self.inner = Arc::new(LinkedList::clone(&self.inner));
+        }
+
+        match Arc::get_mut(&mut self.inner) {
+            Some(list) => list,
+            None => unreachable!("freshly copied list is shared"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Clone for SyncSharedList<T> {
+    fn clone(&self) -> Self {
+        SyncSharedList { inner: Arc::clone(&self.inner) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Deref for SyncSharedList<T> {
+    type Target = LinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<LinkedList<T>> for SyncSharedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        SyncSharedList::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> LinkedList<i32> {
+        let mut list = LinkedList::new();
+        list.extend_fast(1..4);
+        list
+    }
+
+    #[test]
+    fn test_clone_shares_list() {
+        let shared = SharedList::new(sample());
+        let other = shared.clone();
+
+        assert!(SharedList::ptr_eq(&shared, &other));
+        assert_eq!(other.len(), 3);
+        assert!(other.contains(&2));
+    }
+
+    #[test]
+    fn test_make_mut_unique_does_not_clone() {
+        let mut shared = SharedList::new(sample());
+        let first = shared.iter().next().unwrap() as *const i32;
+
+        shared.make_mut().push_back(4);
+
+        assert_eq!(shared.iter().next().unwrap() as *const i32, first);
+        assert_eq!(format!("{:?}", *shared), "1 -> 2 -> 3 -> 4 -> End");
+    }
+
+    #[test]
+    fn test_make_mut_shared_clones() {
+        let mut shared = SharedList::new(sample());
+        let other = shared.clone();
+        let first = other.iter().next().unwrap() as *const i32;
+
+        shared.make_mut().push_front(0);
+
+        assert!(!SharedList::ptr_eq(&shared, &other));
+        assert_eq!(format!("{:?}", *shared), "0 -> 1 -> 2 -> 3 -> End");
+        assert_eq!(format!("{:?}", *other), "1 -> 2 -> 3 -> End");
+        assert_eq!(other.iter().next().unwrap() as *const i32, first);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sync_make_mut() {
+        let mut shared = SyncSharedList::new(sample());
+        let other = shared.clone();
+        let second = shared.iter().nth(1).unwrap() as *const i32;
+
+        let handle = std::thread::spawn(move || other.len());
+        assert_eq!(handle.join().unwrap(), 3);
+
+        // The other handle has been dropped by the thread, so no copy is made.
+        shared.make_mut().pop_front();
+        assert_eq!(shared.len(), 2);
+        assert_eq!(shared.iter().next().unwrap() as *const i32, second);
+
+        let other = shared.clone();
+        shared.make_mut().push_back(9);
+        assert_eq!(format!("{:?}", *shared), "2 -> 3 -> 9 -> End");
+        assert_eq!(format!("{:?}", *other), "2 -> 3 -> End");
+    }
+}
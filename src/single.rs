@@ -1,6 +1,24 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+use std::hash::Hash;
+use std::iter::FusedIterator;
+use std::mem::MaybeUninit;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+use std::ops::{Add, AddAssign, Index, IndexMut, Range};
+use std::rc::Rc;
+
+use crate::stats::Counters;
+use crate::testing::ListDiff;
+use crate::ConsistencyError;
+
 /// Type alias for an optional boxed node, simplifying the type signature.
 type OptionNode<T> = Option<Box<Node<T>>>;
 
+/// Node allocations reserved ahead of use; see [`LinkedList::reserve`].
+type SpareNodes<T> = Vec<Box<MaybeUninit<Node<T>>>>;
+
 /// A node in the singly linked list.
 ///
 /// Each node holds its own data of generic type `T` and a pointer (optional) to the next node in the list.
@@ -37,12 +55,142 @@ impl<T> Node<T> {
 pub struct LinkedList<T> {
     head: OptionNode<T>,
     length: usize,
+    /// Allocated but unused nodes, consumed by the next insertions.
+    spare: SpareNodes<T>,
+    /// Operation counters, only recorded with the `stats` feature.
+    stats: Counters,
 }
 
 impl<T> LinkedList<T> {
     /// Constructs a new, empty LinkedList.
     pub fn new() -> Self {
-        LinkedList { head: None, length: 0 }
+        LinkedList { head: None, length: 0, spare: Vec::new(), stats: Counters::default() }
+    }
+
+    /// Constructs a new, empty list with `capacity` nodes allocated up front.
+    ///
+    /// See [`LinkedList::reserve`] for how the reserved nodes are used.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of nodes to allocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut list = LinkedList::new();
+        list.reserve(capacity);
+        list
+    }
+
+    /// Allocates nodes so that at least `additional` more elements can be inserted
+    /// without allocating.
+    ///
+    /// The reserved nodes hold no element, so `len()` is unchanged and dropping
+    /// the list frees them without running any destructor of `T`. They are used up
+    /// by [`LinkedList::push_front`], [`LinkedList::push_back`],
+    /// [`LinkedList::insert`] and by extending or collecting the list; other
+    /// insertions allocate as usual.
+    ///
+    /// # Arguments
+    ///
+    /// * `additional` - The number of insertions to prepare for.
+    pub fn reserve(&mut self, additional: usize) {
+        let missing = additional.saturating_sub(self.spare.len());
+        self.spare.reserve(missing);
+        for _ in 0..missing {
+            self.spare.push(Box::new_uninit());
+        }
+        self.stats.allocated(missing);
+    }
+
+    /// Returns the number of elements the list can hold without allocating a node.
+    pub fn capacity(&self) -> usize {
+        self.length + self.spare.len()
+    }
+
+    /// Frees every reserved node that is not in use.
+    pub fn shrink_to_fit(&mut self) {
+        self.stats.freed(self.spare.len());
+        self.spare = Vec::new();
+    }
+
+    /// Boxes `node`, reusing a reserved allocation if there is one.
+    fn alloc_node(spare: &mut SpareNodes<T>, stats: &Counters, node: Node<T>) -> Box<Node<T>> {
+        match spare.pop() {
+            Some(slot) => Box::write(slot, node),
+            None => {
+                stats.allocated(1);
+                Box::new(node)
+            }
+        }
+    }
+
+    /// Constructs a list of `n` elements, the element at each index being `f(index)`.
+    ///
+    /// `f` is called exactly once for each index, in order from 0 to `n - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of elements.
+    /// * `f` - Produces the element for an index.
+    pub fn from_fn<F: FnMut(usize) -> T>(n: usize, f: F) -> Self {
+        (0..n).map(f).collect()
+    }
+
+    /// Constructs a list of `n` clones of `elem`.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The element to repeat.
+    /// * `n` - The number of elements.
+    pub fn repeat(elem: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        std::iter::repeat_n(elem, n).collect()
+    }
+
+    /// Constructs a list from the `delim`-separated records of `reader`, parsing each one.
+    ///
+    /// Records are read and appended one at a time behind a tail cursor, so no
+    /// intermediate collection is built and each append is O(1). A trailing
+    /// delimiter does not produce an empty last record.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the records.
+    /// * `delim` - The byte separating the records.
+    /// * `parse` - Converts a record, without its delimiter, into an element.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first failing record and returns its 1-based number with
+    /// `FromReadError::Io` for read errors and records that are not valid UTF-8,
+    /// or with `FromReadError::Parse` for the error returned by `parse`.
+    #[cfg(feature = "std")]
+    pub fn from_delimited<R, E, F>(reader: R, delim: u8, mut parse: F) -> Result<Self, FromReadError<E>>
+    where
+        R: BufRead,
+        F: FnMut(&str) -> Result<T, E>,
+    {
+        let records = reader.split(delim).enumerate().map(|(index, record)| {
+            let line = index + 1;
+            let record = record.map_err(|error| FromReadError::Io { line, error })?;
+            let record = std::str::from_utf8(&record)
+                .map_err(|err| FromReadError::Io { line, error: io::Error::new(io::ErrorKind::InvalidData, err) })?;
+            parse(record).map_err(|error| FromReadError::Parse { line, error })
+        });
+        Self::try_collect(records)
+    }
+
+    /// Collects the values of `iter` into a new list, stopping at the first error.
+    #[cfg(feature = "std")]
+    fn try_collect<E, I: Iterator<Item = Result<T, E>>>(iter: I) -> Result<Self, E> {
+        let mut error = None;
+        let mut list = LinkedList::new();
+        list.link_at_tail(iter.map_while(|result| result.map_err(|err| error = Some(err)).ok()));
+        match error {
+            Some(err) => Err(err),
+            None => Ok(list),
+        }
     }
 
     /// Inserts an element at the start of the list.
@@ -50,31 +198,189 @@ impl<T> LinkedList<T> {
     /// # Arguments
     ///
     /// * `elem` - The element to be added to the list.
-    pub fn push(&mut self, elem: T) {
-        let new_node = Box::new(Node {
+    pub fn push_front(&mut self, elem: T) {
+        let new_node = Self::alloc_node(&mut self.spare, &self.stats, Node {
             data: elem,
             next: self.head.take(),
         });
 
         self.head = Some(new_node);
-        self.length += 1;
+        grow(&mut self.length, 1, "push_front");
+    }
+
+    /// Inserts an element at the start of the list.
+    #[deprecated(note = "renamed to `push_front` to match `doubly::LinkedList`")]
+    pub fn push(&mut self, elem: T) {
+        self.push_front(elem);
     }
 
     /// Appends an element to the end of the list.
     ///
+    /// This walks the whole list to find the tail, so it takes O(n) time.
+    ///
     /// # Arguments
     ///
     /// * `elem` - The element to be appended to the list.
-    pub fn append(&mut self, elem: T) {
-        let new_node = Box::new(Node::new(elem));
+    pub fn push_back(&mut self, elem: T) {
+        let new_node = Self::alloc_node(&mut self.spare, &self.stats, Node::new(elem));
 
         let mut cursor = &mut self.head;
+        let mut steps = 0;
         while let Some(ref mut next_node) = *cursor {
             cursor = &mut next_node.next;
+            steps += 1;
         }
 
         *cursor = Some(new_node);
-        self.length += 1;
+        grow(&mut self.length, 1, "push_back");
+        self.stats.traversed(steps);
+    }
+
+    /// Appends an element to the end of the list.
+    #[deprecated(note = "renamed to `push_back` to match `doubly::LinkedList`")]
+    pub fn append(&mut self, elem: T) {
+        self.push_back(elem);
+    }
+
+    /// Inserts an element at the start of the list, reporting allocation failure.
+    ///
+    /// Unlike [`LinkedList::push_front`], running out of memory does not abort the
+    /// process: the list is left unchanged and the element is handed back.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The element to be added to the list.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or the allocation error together with `elem`.
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_front(&mut self, elem: T) -> Result<(), (AllocError, T)> {
+        let mut new_node = try_box(Node::new(elem)).map_err(|node| (AllocError, node.data))?;
+        new_node.next = self.head.take();
+        self.head = Some(new_node);
+        grow(&mut self.length, 1, "try_push_front");
+        self.stats.allocated(1);
+        Ok(())
+    }
+
+    /// Appends an element to the end of the list, reporting allocation failure.
+    ///
+    /// Like [`LinkedList::push_back`] this walks the whole list, but the node is
+    /// allocated first: on failure the list is left unchanged and the element is
+    /// handed back.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The element to be appended to the list.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or the allocation error together with `elem`.
+    #[cfg(feature = "fallible-alloc")]
+    pub fn try_push_back(&mut self, elem: T) -> Result<(), (AllocError, T)> {
+        let new_node = try_box(Node::new(elem)).map_err(|node| (AllocError, node.data))?;
+        *self.tail_link() = Some(new_node);
+        grow(&mut self.length, 1, "try_push_back");
+        self.stats.allocated(1);
+        Ok(())
+    }
+
+    /// Appends every element of an exact-size iterator to the end of the list.
+    ///
+    /// This is the bulk-construction fast path: the tail is located once and the
+    /// nodes are linked in a tight loop, with `length` updated as each node is linked.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The elements to be appended, in order.
+    ///
+    /// # Panics
+    ///
+    /// If `iter` panics, the elements it produced before panicking stay appended
+    /// and counted in `len()`.
+    pub fn extend_fast<I: ExactSizeIterator<Item = T>>(&mut self, iter: I) {
+        let expected = iter.len();
+        let added = self.link_at_tail(iter);
+        debug_assert_eq!(added, expected, "ExactSizeIterator reported a wrong length");
+    }
+
+    /// Moves every element of `other` to the end of the list.
+    ///
+    /// The nodes of `other` are relinked rather than reallocated, so no element is
+    /// moved or cloned; the only cost is the walk to the current tail.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list whose elements are appended, in order.
+    pub fn extend_list(&mut self, mut other: LinkedList<T>) {
+        self.splice_back(&mut other, "extend_list");
+    }
+
+    /// Moves every element of `other` to the end of the list, leaving `other` empty.
+    ///
+    /// Like [`LinkedList::extend_list`], but `other` is borrowed so it can be
+    /// reused. The chains are joined by relinking after one walk to the tail of
+    /// `self`, so this takes O(len) time whatever the length of `other`. The
+    /// exclusive borrows rule out appending a list to itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list whose elements are appended, in order.
+    pub fn append_list(&mut self, other: &mut LinkedList<T>) {
+        self.splice_back(other, "append_list");
+    }
+
+    /// Links the elements produced by `iter` after the last node of the list.
+    ///
+    /// The length is updated as each node is linked, so if `iter` panics the
+    /// elements produced so far stay in the list and are counted.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements added.
+    pub(crate) fn link_at_tail<I: Iterator<Item = T>>(&mut self, iter: I) -> usize {
+        let mut cursor = &mut self.head;
+        let mut steps = 0;
+        while let Some(node) = cursor {
+            cursor = &mut node.next;
+            steps += 1;
+        }
+        self.stats.traversed(steps);
+
+        let mut added = 0;
+        for elem in iter {
+            cursor = &mut cursor.insert(Self::alloc_node(&mut self.spare, &self.stats, Node::new(elem))).next;
+            grow(&mut self.length, 1, "extend");
+            added += 1;
+        }
+
+        added
+    }
+
+    /// Moves every node of `other` to the end of the list, leaving `other` empty.
+    ///
+    /// The chains are joined by relinking, after a single walk to the tail of `self`.
+    fn splice_back(&mut self, other: &mut LinkedList<T>, operation: &str) {
+        grow(&mut self.length, other.length, operation);
+        other.length = 0;
+        if let Some(chain) = other.head.take() {
+            *self.tail_link() = Some(chain);
+            self.stats.relinked(1);
+        }
+    }
+
+    /// Returns the empty link after the last node of the list.
+    fn tail_link(&mut self) -> &mut OptionNode<T> {
+        let mut cursor = &mut self.head;
+        let mut steps = 0;
+        while let Some(node) = cursor {
+            cursor = &mut node.next;
+            steps += 1;
+        }
+
+        self.stats.traversed(steps);
+        cursor
     }
 
     /// Removes and returns the first element of the list, if it exists.
@@ -82,213 +388,5529 @@ impl<T> LinkedList<T> {
     /// # Returns
     ///
     /// The removed element, if the list was not empty.
-    pub fn pop(&mut self) -> Option<T> {
+    pub fn pop_front(&mut self) -> Option<T> {
         let res = self.head.take().map(|node| {
             self.head = node.next;
             node.data
         });
 
         if res.is_some() {
-            self.length = self.length.saturating_sub(1);
+            shrink(&mut self.length, 1);
+            self.stats.freed(1);
         }
 
         res
     }
 
+    /// Removes and returns the first element of the list, if it exists.
+    #[deprecated(note = "renamed to `pop_front` to match `doubly::LinkedList`")]
+    pub fn pop(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
     /// Removes and returns the last element of the list, if it exists.
     ///
     /// # Returns
     ///
     /// The removed element, if the list was not empty.
     pub fn pop_back(&mut self) -> Option<T> {
-        if self.head.is_none() {
-            return None;
-        }
+        self.head.as_ref()?;
 
         let mut cursor = &mut self.head;
+        let mut steps = 0;
         while cursor.as_ref()?.next.is_some() {
             cursor = &mut cursor.as_mut()?.next;
+            steps += 1;
         }
 
         let res = cursor.take().map(|node| node.data);
+        self.stats.traversed(steps);
         if res.is_some() {
-            let _ = self.length.saturating_sub(1);
+            shrink(&mut self.length, 1);
+            self.stats.freed(1);
         }
 
         res
     }
 
-    /// Returns the current length of the list.
-    ///
-    /// # Returns
+    /// Provides a reference to the first element of the list, if it exists.
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_deref().map(|node| &node.data)
+    }
+
+    /// Provides a mutable reference to the first element of the list, if it exists.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.as_deref_mut().map(|node| &mut node.data)
+    }
+
+    /// Provides a reference to the last element of the list, if it exists.
     ///
-    /// The number of elements in the list.
-    pub fn len(&self) -> usize {
-        self.length
+    /// The list keeps no tail pointer, so this walks the whole list.
+    pub fn back(&self) -> Option<&T> {
+        self.first_last().map(|(_, last)| last)
     }
 
-    /// Clears the list, removing all elements.
-    pub fn clear(&mut self) {
-        self.head = None;
-        self.length = 0;
+    /// Provides a mutable reference to the last element of the list, if it exists.
+    ///
+    /// This is the same as [`LinkedList::last_mut`] and walks the whole list.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.last_mut()
     }
 
-    /// Checks if the list is empty.
+    /// Provides a mutable reference to the last element of the list, if it exists.
     ///
     /// # Returns
     ///
-    /// `true` if the list contains no elements, `false` otherwise.
-    pub fn is_empty(&self) -> bool {
-        self.length == 0
+    /// A mutable reference to the last element, found in one traversal.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        let mut node = self.head.as_deref_mut()?;
+        while node.next.is_some() {
+            node = node.next.as_deref_mut()?;
+        }
+
+        Some(&mut node.data)
     }
 
-    /// Provides an iterator over the list's elements.
+    /// Provides references to both the first and the last element of the list.
     ///
     /// # Returns
     ///
-    /// An iterator that yields references to the elements in the list.
-    pub fn iter(&self) -> Iter<T> {
-        Iter {
-            next: match &self.head {
-                None => None,
-                Some(node) => Some(&node),
-            },
+    /// The first and last elements, found in one traversal, or `None` if the list
+    /// is empty. For a single-element list both references point to the same element.
+    pub fn first_last(&self) -> Option<(&T, &T)> {
+        let first = self.head.as_deref()?;
+        let mut last = first;
+        while let Some(next) = last.next.as_deref() {
+            last = next;
         }
+
+        Some((&first.data, &last.data))
     }
-}
 
-/// Implementation of Debug trait to enable printing of the list for debugging purposes.
-impl<T> std::fmt::Debug for LinkedList<T> where T: std::fmt::Debug {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut current = &self.head;
-        while let Some(node) = current {
-            write!(f, "{:?} -> ", node.data)?;
-            current = &node.next;
+    /// Provides a reference to the element at `index`.
+    ///
+    /// This follows `index` links from the head and allocates nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the element.
+    ///
+    /// # Returns
+    ///
+    /// The element at `index`, or `None` if `index >= len()`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.length {
+            return None;
         }
-        write!(f, "End")
+        self.stats.traversed(index);
+        self.iter().nth(index)
     }
-}
-
-/// Iterator over the elements of a `LinkedList`.
-pub struct Iter<'a, T> {
-    next: Option<&'a Node<T>>,
-}
-
-/// Implementation of the Iterator trait for Iter.
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.next.map(|node| {
-            self.next = match &node.next {
-                None =>
-                    None,
-                Some(next_node) => Some(&next_node),
-            };
-            &node.data
-        })
+    /// Provides a mutable reference to the element at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the element.
+    ///
+    /// # Returns
+    ///
+    /// The element at `index`, or `None` if `index >= len()`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.length {
+            return None;
+        }
+        self.link_mut(index).as_deref_mut().map(|node| &mut node.data)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Inserts an element at position `index`, shifting the elements after it back.
+    ///
+    /// `insert(0, elem)` is equivalent to `push_front(elem)` and
+    /// `insert(len(), elem)` to `push_back(elem)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position the element ends up at, at most `len()`.
+    /// * `elem` - The element to be inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len()`.
+    pub fn insert(&mut self, index: usize, elem: T) {
+        assert!(index <= self.length, "insert: index {} out of range for list of length {}", index, self.length);
 
-    // Test the creation of a new LinkedList.
-    #[test]
-    fn test_new() {
-        let list: LinkedList<i32> = LinkedList::new();
-        assert!(list.is_empty());
+        let mut node = Self::alloc_node(&mut self.spare, &self.stats, Node::new(elem));
+        let link = self.link_mut(index);
+        node.next = link.take();
+        *link = Some(node);
+        grow(&mut self.length, 1, "insert");
     }
 
-    // Test pushing elements onto the front of the list.
-    #[test]
-    fn test_push() {
-        let mut list = LinkedList::new();
-        list.push(1);
-        list.push(2);
-        list.push(3);
+    /// Removes and returns the element at position `index`.
+    ///
+    /// `remove(0)` is equivalent to `pop_front()` and `remove(len() - 1)` to
+    /// `pop_back()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the element.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or `None` if `index >= len()`, in which case the list
+    /// is left unchanged.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
+        }
 
-        assert_eq!(list.len(), 3);
-        assert_eq!(format!("{:?}", list), "3 -> 2 -> 1 -> End");
+        let link = self.link_mut(index);
+        let node = link.take()?;
+        *link = node.next;
+        shrink(&mut self.length, 1);
+        self.stats.freed(1);
+        Some(node.data)
     }
 
-    // Test popping elements from the front of the list.
-    #[test]
-    fn test_pop() {
-        let mut list = LinkedList::new();
-        list.push(1);
-        list.push(2);
-        list.push(3);
-
-        assert_eq!(list.pop(), Some(3));
-        assert_eq!(list.pop(), Some(2));
-        assert_eq!(list.pop(), Some(1));
-        assert_eq!(list.pop(), None);
+    /// Provides a reference to the element at `index`, or to the last element if `index` is past the end.
+    ///
+    /// Unlike a strict index lookup, this only returns `None` when the list is empty,
+    /// which suits callers asking for "the element at `index`, or the nearest valid one".
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The requested position.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the element at `min(index, len() - 1)`, or `None` if the list is empty.
+    pub fn get_clamped(&self, index: usize) -> Option<&T> {
+        let last = self.length.checked_sub(1)?;
+        self.iter().nth(index.min(last))
     }
 
-    // Test appending elements to the end of the list.
-    #[test]
-    fn test_append() {
-        let mut list = LinkedList::new();
-        list.append(1);
-        list.append(2);
-        list.append(3);
+    /// Returns the current length of the list.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.length
+    }
 
-        assert_eq!(list.len(), 3);
-        assert_eq!(format!("{:?}", list), "1 -> 2 -> 3 -> End");
+    /// Clears the list, removing all elements.
+    pub fn clear(&mut self) {
+        let chain = self.head.take();
+        self.free_chain(chain);
+        self.length = 0;
     }
 
-    // Test popping elements from the back of the list.
-    #[test]
-    fn test_pop_back() {
+    /// Shortens the list to its first `len` elements, dropping the rest.
+    ///
+    /// Does nothing if the list holds `len` elements or fewer; `truncate(0)` is
+    /// the same as [`LinkedList::clear`].
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of elements to keep.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.length {
+            return;
+        }
+        let suffix = self.link_mut(len).take();
+        self.free_chain(suffix);
+        self.length = len;
+    }
+
+    /// Drops every node of `chain`.
+    fn free_chain(&self, mut chain: OptionNode<T>) {
+        // Unlink the nodes one by one so long chains don't overflow the stack with recursive drops.
+        while let Some(mut node) = chain {
+            chain = node.next.take();
+            self.stats.freed(1);
+        }
+    }
+
+    /// Checks if the list is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the list contains no elements, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Checks if the list contains an element equal to `value`.
+    ///
+    /// The value may be any borrowed form of the element type, so a
+    /// `LinkedList<String>` can be searched with a `&str`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to search for.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any element is equal to `value`, `false` otherwise.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.iter().any(|elem| elem.borrow() == value)
+    }
+
+    /// Counts the elements equal to `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to count, in any borrowed form of the element type.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements equal to `value`.
+    pub fn count_of<Q>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.iter().filter(|elem| (*elem).borrow() == value).count()
+    }
+
+    /// Returns the first element that satisfies `pred`.
+    ///
+    /// The search stops at the first match.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Returns `true` for the element being searched for.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the first matching element, or `None` if there is none.
+    pub fn find<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Option<&T> {
+        self.iter().find(|elem| pred(elem))
+    }
+
+    /// Returns the first element that satisfies `pred`, mutably.
+    ///
+    /// The search stops at the first match.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Returns `true` for the element being searched for.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the first matching element, or `None` if there is none.
+    pub fn find_mut<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> Option<&mut T> {
+        self.iter_mut().find(|elem| pred(elem))
+    }
+
+    /// Searches for `seq` as a contiguous run of elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `seq` - The values to search for, in any borrowed form of the element type.
+    ///
+    /// # Returns
+    ///
+    /// The index at which the first occurrence of `seq` starts, if any.
+    /// An empty sequence is found at index 0.
+    pub fn find_seq<'q, Q, I>(&self, seq: I) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized + 'q,
+        I: IntoIterator<Item = &'q Q>,
+        I::IntoIter: Clone,
+    {
+        let seq = seq.into_iter();
+        if seq.clone().next().is_none() {
+            return Some(0);
+        }
+
+        let mut start = self.head.as_deref();
+        let mut index = 0;
+        while let Some(node) = start {
+            let mut current = Some(node);
+            let matched = seq.clone().all(|value| match current {
+                Some(candidate) if candidate.data.borrow() == value => {
+                    current = candidate.next.as_deref();
+                    true
+                }
+                _ => false,
+            });
+            if matched {
+                return Some(index);
+            }
+
+            start = node.next.as_deref();
+            index += 1;
+        }
+
+        None
+    }
+
+    /// Removes and returns the first element equal to `value`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to remove, in any borrowed form of the element type.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, if a matching element was found.
+    pub fn remove_first<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        let mut cursor = &mut self.head;
+        while cursor.as_ref()?.data.borrow() != value {
+            cursor = &mut cursor.as_mut()?.next;
+            self.stats.traversed(1);
+        }
+
+        let node = cursor.take()?;
+        *cursor = node.next;
+        shrink(&mut self.length, 1);
+        self.stats.freed(1);
+
+        Some(node.data)
+    }
+
+    /// Converts the list into a doubly linked list with the same elements in the same order.
+    ///
+    /// No element is cloned. The nodes of the two list types have different layouts,
+    /// so each payload is moved into a newly allocated doubly-linked node.
+    ///
+    /// # Returns
+    ///
+    /// A doubly linked list holding the elements of this list.
+    pub fn into_doubly(mut self) -> crate::doubly::LinkedList<T> {
+        let mut doubly = crate::doubly::LinkedList::new();
+        while let Some(elem) = self.pop_front() {
+            doubly.push_back(elem);
+        }
+
+        doubly
+    }
+
+    /// Formats the elements with `sep` between each pair, like `[T]::join`.
+    ///
+    /// # Arguments
+    ///
+    /// * `sep` - The separator placed between consecutive elements.
+    ///
+    /// # Returns
+    ///
+    /// The joined string, which is empty for an empty list.
+    pub fn join(&self, sep: &str) -> String
+    where
+        T: std::fmt::Display,
+    {
+        let mut joined = String::new();
+        match self.write_joined(&mut joined, sep) {
+            Ok(()) => joined,
+            Err(_) => panic!("a Display implementation returned an error unexpectedly"),
+        }
+    }
+
+    /// Writes the elements to `out` with `sep` between each pair.
+    fn write_joined<W: std::fmt::Write>(&self, out: &mut W, sep: &str) -> std::fmt::Result
+    where
+        T: std::fmt::Display,
+    {
+        for (index, elem) in self.iter().enumerate() {
+            if index > 0 {
+                out.write_str(sep)?;
+            }
+            write!(out, "{}", elem)?;
+        }
+        Ok(())
+    }
+
+    /// Decomposes the list into its chain of nodes and its length.
+    ///
+    /// The list can be rebuilt with [`LinkedList::from_raw_parts`]. A chain dropped
+    /// on its own is freed recursively, so long chains should be turned back into a
+    /// list before they are dropped.
+    ///
+    /// # Returns
+    ///
+    /// The head node, which owns the rest of the chain, and the number of nodes.
+    pub fn into_raw_parts(mut self) -> (Option<Box<Node<T>>>, usize) {
+        let length = std::mem::take(&mut self.length);
+        (self.head.take(), length)
+    }
+
+    /// Rebuilds a list from a chain of nodes and its length.
+    ///
+    /// The chain is walked once to check that it holds exactly `len` nodes, so a
+    /// wrong length is rejected instead of breaking the length invariant.
+    ///
+    /// # Arguments
+    ///
+    /// * `head` - The first node of the chain, or `None` for an empty list.
+    /// * `len` - The number of nodes in the chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConsistencyError::LengthMismatch`] if the chain holds a different
+    /// number of nodes; the chain is dropped in that case.
+    pub fn from_raw_parts(head: Option<Box<Node<T>>>, len: usize) -> Result<Self, ConsistencyError> {
+        let list = LinkedList { head, length: len, spare: Vec::new(), stats: Counters::default() };
+        let actual = list.count_links();
+        if actual != len {
+            return Err(ConsistencyError::LengthMismatch { expected: len, actual });
+        }
+        Ok(list)
+    }
+
+    /// Leaks the list, returning a mutable reference that lives as long as `'a`.
+    ///
+    /// Like `Vec::leak`, the list and its nodes are never freed, so this is only
+    /// meant for data that lives until the program exits.
+    pub fn leak<'a>(self) -> &'a mut LinkedList<T> {
+        Box::leak(Box::new(self))
+    }
+
+    /// Leaks the nodes one by one, yielding a reference to each element that lives
+    /// as long as `'a`.
+    ///
+    /// Every node is leaked as it is yielded and its memory is never reclaimed, so
+    /// the references stay valid after the list is gone. Elements not yet yielded
+    /// when the iterator is dropped are freed as usual instead.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over references to the leaked elements, front to back.
+    pub fn leak_elements<'a>(mut self) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.length = 0;
+        let mut next = self.head.take();
+        std::iter::from_fn(move || {
+            let node: &'a mut Node<T> = Box::leak(next.take()?);
+            next = node.next.take();
+            Some(&mut node.data)
+        })
+    }
+
+    /// Moves the elements into a vector and sorts it.
+    ///
+    /// Sorting a contiguous vector is usually much faster than sorting by relinking
+    /// nodes, so this is the preferred way to end up with sorted data. The sort is
+    /// stable and no element is cloned.
+    ///
+    /// # Returns
+    ///
+    /// A vector holding every element of the list in ascending order.
+    pub fn into_sorted_vec(self) -> Vec<T>
+    where
+        T: Ord,
+    {
+        let mut sorted = self.into_unsorted_vec();
+        sorted.sort();
+        sorted
+    }
+
+    /// Moves the elements into a binary heap, for hand-off to a priority queue.
+    ///
+    /// # Returns
+    ///
+    /// A max-heap holding every element of the list.
+    pub fn into_binary_heap(self) -> BinaryHeap<T>
+    where
+        T: Ord,
+    {
+        BinaryHeap::from(self.into_unsorted_vec())
+    }
+
+    /// Moves the elements into a vector, in list order.
+    fn into_unsorted_vec(mut self) -> Vec<T> {
+        let mut elems = Vec::with_capacity(self.length);
+        while let Some(elem) = self.pop_front() {
+            elems.push(elem);
+        }
+
+        elems
+    }
+
+    /// Converts every element with a fallible closure, consuming the list.
+    ///
+    /// Conversion stops at the first error; the elements converted so far and
+    /// the elements not yet visited are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The conversion applied to each element, front to back.
+    ///
+    /// # Returns
+    ///
+    /// The list of converted elements, or the index of the failing element
+    /// together with its error.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the element it was given, the elements converted so far and
+    /// the elements not yet visited are each dropped exactly once.
+    pub fn try_map<U, E, F>(mut self, mut f: F) -> Result<LinkedList<U>, (usize, E)>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        let mut mapped = LinkedList::new();
+        let mut failure = None;
+        mapped.link_at_tail(std::iter::from_fn(|| {
+            let elem = self.pop_front()?;
+            match f(elem) {
+                Ok(converted) => Some(converted),
+                Err(err) => {
+                    failure = Some(err);
+                    None
+                }
+            }
+        }));
+
+        match failure {
+            Some(err) => Err((mapped.len(), err)),
+            None => Ok(mapped),
+        }
+    }
+
+    /// Checks that the list shares no element with `other`.
+    ///
+    /// This compares every pair of elements, so it takes O(n·m) time; see
+    /// [`LinkedList::is_disjoint_hashed`] for larger lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to compare against.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no element of the list is equal to an element of `other`.
+    pub fn is_disjoint(&self, other: &LinkedList<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        !self.iter().any(|elem| other.iter().any(|candidate| candidate == elem))
+    }
+
+    /// Checks that the list shares no element with `other`, using a hash set.
+    ///
+    /// The smaller list is collected into a `HashSet`, so this takes O(n + m) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to compare against.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no element of the list is equal to an element of `other`.
+    pub fn is_disjoint_hashed(&self, other: &LinkedList<T>) -> bool
+    where
+        T: Hash + Eq,
+    {
+        let (smaller, larger) = if self.len() <= other.len() { (self, other) } else { (other, self) };
+        let seen: HashSet<&T> = smaller.iter().collect();
+        !larger.iter().any(|elem| seen.contains(elem))
+    }
+
+    /// Checks whether any element occurs more than once in the list.
+    ///
+    /// This compares every pair of elements, so it takes O(n²) time; see
+    /// [`LinkedList::has_duplicates_hashed`] for larger lists.
+    ///
+    /// # Returns
+    ///
+    /// `true` if two elements of the list are equal.
+    pub fn has_duplicates(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.first_duplicate().is_some()
+    }
+
+    /// Finds the first element that repeats an earlier one.
+    ///
+    /// This compares every pair of elements, so it takes O(n²) time; see
+    /// [`LinkedList::first_duplicate_hashed`] for larger lists.
+    ///
+    /// # Returns
+    ///
+    /// The index of the earliest second occurrence and a reference to that element,
+    /// or `None` if all elements are distinct.
+    pub fn first_duplicate(&self) -> Option<(usize, &T)>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .enumerate()
+            .find(|&(index, elem)| self.iter().take(index).any(|earlier| earlier == elem))
+    }
+
+    /// Checks whether any element occurs more than once in the list, using a hash set.
+    ///
+    /// # Returns
+    ///
+    /// `true` if two elements of the list are equal.
+    pub fn has_duplicates_hashed(&self) -> bool
+    where
+        T: Hash + Eq,
+    {
+        self.first_duplicate_hashed().is_some()
+    }
+
+    /// Finds the first element that repeats an earlier one, using a hash set.
+    ///
+    /// Elements are recorded in a `HashSet` as they are visited, so this takes O(n) time.
+    ///
+    /// # Returns
+    ///
+    /// The index of the earliest second occurrence and a reference to that element,
+    /// or `None` if all elements are distinct.
+    pub fn first_duplicate_hashed(&self) -> Option<(usize, &T)>
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::with_capacity(self.length);
+        self.iter().enumerate().find(|&(_, elem)| !seen.insert(elem))
+    }
+
+    /// Removes every element equal to an earlier one, keeping first occurrences in order.
+    ///
+    /// Unlike removing consecutive repeats, this catches duplicates anywhere in the
+    /// list. Seen elements are recorded in a `HashSet`, so this takes O(n) time and
+    /// O(n) extra space; the duplicates are then unlinked in one forward pass.
+    pub fn dedup_all(&mut self)
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::with_capacity(self.length);
+        let duplicate: Vec<bool> = self.iter().map(|elem| !seen.insert(elem)).collect();
+        drop(self.extract_by_index(|index| duplicate[index]));
+    }
+
+    /// Removes every element equal to an earlier one, comparing with `PartialEq` only.
+    ///
+    /// This is the fallback for [`LinkedList::dedup_all`] when `T` is not hashable:
+    /// each element is compared with every earlier one, which takes O(n²) time.
+    pub fn dedup_all_by_eq(&mut self)
+    where
+        T: PartialEq,
+    {
+        let elems: Vec<&T> = self.iter().collect();
+        let duplicate: Vec<bool> = elems.iter().enumerate().map(|(index, elem)| elems[..index].contains(elem)).collect();
+        drop(self.extract_by_index(|index| duplicate[index]));
+    }
+
+    /// Checks element-wise equality with `other` using a custom predicate.
+    ///
+    /// This mirrors `Iterator::eq_by`: `other` may be another list's iterator, a
+    /// slice, or any iterator, and sequences of different lengths are never equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The elements to compare against, in order.
+    /// * `eq` - Decides whether an element of the list matches one of `other`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if both sequences have the same length and every pair matches.
+    pub fn eq_by<U, I, F>(&self, other: I, mut eq: F) -> bool
+    where
+        I: IntoIterator<Item = U>,
+        F: FnMut(&T, &U) -> bool,
+    {
+        let mut other = other.into_iter();
+        for elem in self.iter() {
+            match other.next() {
+                Some(theirs) if eq(elem, &theirs) => {}
+                _ => return false,
+            }
+        }
+
+        other.next().is_none()
+    }
+
+    /// Compares the list lexicographically with `other` using a custom ordering.
+    ///
+    /// This mirrors `Iterator::cmp_by`: the first non-equal pair decides, and if one
+    /// sequence is a prefix of the other, the shorter one compares less.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The elements to compare against, in order.
+    /// * `cmp` - Orders an element of the list against one of `other`.
+    ///
+    /// # Returns
+    ///
+    /// The ordering of the list relative to `other`.
+    pub fn cmp_by<U, I, F>(&self, other: I, mut cmp: F) -> Ordering
+    where
+        I: IntoIterator<Item = U>,
+        F: FnMut(&T, &U) -> Ordering,
+    {
+        let mut other = other.into_iter();
+        for elem in self.iter() {
+            let theirs = match other.next() {
+                Some(theirs) => theirs,
+                None => return Ordering::Greater,
+            };
+            match cmp(elem, &theirs) {
+                Ordering::Equal => {}
+                unequal => return unequal,
+            }
+        }
+
+        if other.next().is_some() {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Compares the list with `other` and describes where they first differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to compare against.
+    ///
+    /// # Returns
+    ///
+    /// A [`ListDiff`] that is empty if the lists are equal and otherwise records
+    /// the first differing index, the elements there, and both lengths.
+    pub fn diff<'a>(&'a self, other: &'a LinkedList<T>) -> ListDiff<'a, T>
+    where
+        T: PartialEq + std::fmt::Debug,
+    {
+        ListDiff::between(self.iter(), other.iter())
+    }
+
+    /// Builds a new list holding `n` copies of this list, one after another.
+    ///
+    /// Each copy is cloned in a single pass, so the whole operation is O(n·len).
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of copies; `0` yields an empty list.
+    ///
+    /// # Returns
+    ///
+    /// A list of length `n * self.len()`.
+    pub fn repeat_list(&self, n: usize) -> LinkedList<T>
+    where
+        T: Clone,
+    {
+        let mut repeated = LinkedList::new();
+        repeated.link_at_tail((0..n).flat_map(|_| self.iter().cloned()));
+        repeated
+    }
+
+    /// Provides an iterator that cycles over the list's elements forever.
+    ///
+    /// # Returns
+    ///
+    /// An endless iterator over references to the elements, or an iterator that
+    /// yields nothing if the list is empty.
+    pub fn iter_cycle(&self) -> std::iter::Cycle<Iter<'_, T>> {
+        self.iter().cycle()
+    }
+
+    /// Keeps only the elements at indices `0, n, 2n, …`, dropping the rest in one pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The decimation step; `1` keeps every element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn keep_every_nth(&mut self, n: usize) {
+        assert!(n != 0, "keep_every_nth: n must be non-zero");
+        drop(self.extract_by_index(|index| index % n != 0));
+    }
+
+    /// Removes the elements at indices `0, n, 2n, …` in one pass and returns them.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The decimation step; `1` removes every element.
+    ///
+    /// # Returns
+    ///
+    /// A list of the removed elements, in their original order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn remove_every_nth(&mut self, n: usize) -> LinkedList<T> {
+        assert!(n != 0, "remove_every_nth: n must be non-zero");
+        self.extract_by_index(|index| index % n == 0)
+    }
+
+    /// Finds the index of the element with the largest key.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Extracts the key of an element.
+    ///
+    /// # Returns
+    ///
+    /// The index of the first element with the maximum key, or `None` if the list is empty.
+    pub fn position_max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, f: F) -> Option<usize> {
+        self.position_by_key(f, Ordering::Greater)
+    }
+
+    /// Finds the index of the element with the smallest key.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Extracts the key of an element.
+    ///
+    /// # Returns
+    ///
+    /// The index of the first element with the minimum key, or `None` if the list is empty.
+    pub fn position_min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, f: F) -> Option<usize> {
+        self.position_by_key(f, Ordering::Less)
+    }
+
+    /// Finds the first index whose key compares as `wanted` against every earlier key.
+    fn position_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut f: F, wanted: Ordering) -> Option<usize> {
+        let mut best: Option<(usize, K)> = None;
+        for (index, elem) in self.iter().enumerate() {
+            let key = f(elem);
+            if best.as_ref().is_none_or(|(_, best_key)| key.cmp(best_key) == wanted) {
+                best = Some((index, key));
+            }
+        }
+
+        best.map(|(index, _)| index)
+    }
+
+    /// Reorders the list so that position `i` holds the element previously at `perm[i]`.
+    ///
+    /// The nodes are relinked in the new order without moving their payloads, taking
+    /// O(n) time and O(n) scratch space for the node pointers. The permutation is
+    /// validated before anything is changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `perm` - A permutation of `0..len()` giving the source index of each position.
+    ///
+    /// # Panics
+    ///
+    /// Panics, leaving the list untouched, if `perm` has the wrong length, an index
+    /// out of range or a repeated index.
+    pub fn apply_permutation(&mut self, perm: &[usize]) {
+        let mut seen = vec![false; self.length];
+        let valid = perm.len() == self.length
+            && perm.iter().all(|&src| src < seen.len() && !std::mem::replace(&mut seen[src], true));
+        assert!(valid, "apply_permutation: {:?} is not a permutation of 0..{}", perm, self.length);
+
+        let mut slots = Vec::with_capacity(self.length);
+        let mut rest = self.head.take();
+        while let Some(mut node) = rest {
+            rest = node.next.take();
+            slots.push(Some(node));
+        }
+        for &src in perm.iter().rev() {
+            if let Some(mut node) = slots[src].take() {
+                node.next = self.head.take();
+                self.head = Some(node);
+            }
+        }
+        self.stats.relinked(perm.len());
+    }
+
+    /// Merges the sorted list `other` into this sorted list, keeping the result sorted.
+    ///
+    /// The merge is stable: equal elements keep their order, and those of this list
+    /// come before those of `other`. The nodes of both lists are relinked in a single
+    /// pass, so this takes O(n + m) time and no extra space.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A sorted list whose elements are merged in.
+    pub fn merge_sorted(&mut self, mut other: LinkedList<T>)
+    where
+        T: Ord,
+    {
+        let left = self.head.take();
+        let right = other.head.take();
+        self.length = 0;
+        other.length = 0;
+        Self::merge_into(&mut self.head, left, right, &mut self.length, &self.stats, &mut T::cmp);
+    }
+
+    /// Sorts the list in ascending order.
+    ///
+    /// See [`LinkedList::sort_by`].
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list with a comparator.
+    ///
+    /// This is a bottom-up merge sort over the links: runs of 1, 2, 4, … nodes are
+    /// merged pairwise until one run is left. It takes O(n log n) time, allocates
+    /// nothing and does not recurse. The sort is stable, so equal elements keep
+    /// their relative order.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - The ordering between two elements.
+    ///
+    /// # Panics
+    ///
+    /// If `compare` panics, the list keeps the nodes already merged in the current
+    /// pass, correctly counted, and the others are dropped.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let mut width = 1;
+        while width < self.length {
+            let mut rest = self.head.take();
+            self.length = 0;
+            let mut tail = &mut self.head;
+            while rest.is_some() {
+                let mut left = rest;
+                let mut right = Self::split_chain(&mut left, width);
+                rest = Self::split_chain(&mut right, width);
+                tail = Self::merge_into(tail, left, right, &mut self.length, &self.stats, &mut compare);
+            }
+            width = width.saturating_mul(2);
+        }
+    }
+
+    /// Sorts the list by the key `f` extracts, keeping equal keys in their original order.
+    ///
+    /// `f` is called on every comparison; see [`LinkedList::sort_by_cached_key`] for
+    /// expensive keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Extracts the sort key of an element.
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Links the stable merge of the chains `left` and `right` into the empty link `tail`.
+    ///
+    /// Equal elements are taken from `left` first. Every node is counted in `length`
+    /// as it is linked, so if `compare` panics the linked nodes stay counted.
+    ///
+    /// # Returns
+    ///
+    /// The empty link after the merged chain.
+    fn merge_into<'a, F: FnMut(&T, &T) -> Ordering>(
+        mut tail: &'a mut OptionNode<T>,
+        mut left: OptionNode<T>,
+        mut right: OptionNode<T>,
+        length: &mut usize,
+        stats: &Counters,
+        compare: &mut F,
+    ) -> &'a mut OptionNode<T> {
+        let mut steps = 0;
+        loop {
+            let source = match (&left, &right) {
+                (Some(a), Some(b)) if compare(&b.data, &a.data) == Ordering::Less => &mut right,
+                (Some(_), Some(_)) => &mut left,
+                _ => break,
+            };
+            let mut node = match source.take() {
+                Some(node) => node,
+                None => unreachable!("merge source is empty"),
+            };
+            *source = node.next.take();
+            tail = &mut tail.insert(node).next;
+            grow(length, 1, "merge");
+            steps += 1;
+        }
+        stats.relinked(steps);
+
+        *tail = left.or(right);
+        while let Some(node) = tail {
+            tail = &mut node.next;
+            grow(length, 1, "merge");
+        }
+        tail
+    }
+
+    /// Reverses the order of the elements in place.
+    ///
+    /// The `next` links are re-pointed in a single iterative pass, so this takes
+    /// O(n) time and O(1) extra space; no node is moved or reallocated.
+    pub fn reverse(&mut self) {
+        let mut rest = self.head.take();
+        let mut steps = 0;
+        while let Some(mut node) = rest {
+            rest = node.next.take();
+            node.next = self.head.take();
+            self.head = Some(node);
+            steps += 1;
+        }
+        self.stats.relinked(steps);
+    }
+
+    /// Sorts the list by the key `f` extracts, calling `f` exactly once per element.
+    ///
+    /// The keys are collected into a scratch `Vec` together with each element's
+    /// original position and sorted with `sort_unstable`; the position breaks ties,
+    /// so the sort is stable. The nodes are then relinked in the sorted order. For
+    /// cheap keys this is much faster than a comparison sort over the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Extracts the sort key of an element.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics the list is left untouched, since no node is moved until every
+    /// key has been extracted.
+    pub fn sort_by_cached_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        let mut keyed: Vec<(K, usize)> = self.iter().map(f).zip(0..).collect();
+        keyed.sort_unstable();
+        let perm: Vec<usize> = keyed.into_iter().map(|(_, index)| index).collect();
+        self.apply_permutation(&perm);
+    }
+
+    /// Moves the element at `index` to the front of the list.
+    ///
+    /// The node itself is relinked rather than its payload swapped, so the
+    /// relative order of the other elements is preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the element to be moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn swap_to_front(&mut self, index: usize) {
+        assert!(index < self.length, "swap_to_front: index {} out of range for list of length {}", index, self.length);
+
+        let link = self.link_mut(index);
+        let mut node = match link.take() {
+            Some(node) => node,
+            None => unreachable!("list is shorter than its length"),
+        };
+        *link = node.next.take();
+
+        node.next = self.head.take();
+        self.head = Some(node);
+        self.stats.relinked(1);
+    }
+
+    /// Returns the link that holds the node at `index`, or the final empty link if the list is shorter.
+    fn link_mut(&mut self, index: usize) -> &mut OptionNode<T> {
+        let mut cursor = &mut self.head;
+        let mut steps = 0;
+        while steps < index {
+            match cursor {
+                Some(node) => cursor = &mut node.next,
+                None => break,
+            }
+            steps += 1;
+        }
+
+        self.stats.traversed(steps);
+        cursor
+    }
+
+    /// Divides the list into `n` consecutive parts whose lengths differ by at most one.
+    ///
+    /// The nodes are relinked, not cloned. The first `len() % n` parts receive one
+    /// extra element each, so when `n > len()` the trailing parts are empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of parts.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `n` lists which, concatenated in order, reproduce the original list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn split_into(mut self, n: usize) -> Vec<LinkedList<T>> {
+        assert!(n != 0, "split_into: n must be non-zero");
+
+        let base = self.length / n;
+        let extra = self.length % n;
+        let mut rest = self.head.take();
+        self.length = 0;
+
+        let mut parts = Vec::with_capacity(n);
+        for i in 0..n {
+            let size = base + usize::from(i < extra);
+            let tail = Self::split_chain(&mut rest, size);
+            parts.push(LinkedList { head: rest, length: size, spare: Vec::new(), stats: Counters::default() });
+            rest = tail;
+        }
+
+        parts
+    }
+
+    /// Maps every element by reference on up to `threads` scoped threads.
+    ///
+    /// The list is cut into contiguous segments with the same boundaries as
+    /// [`LinkedList::split_into`], found in one walk from the head. Each segment is
+    /// mapped on its own thread and the results are joined in order, so the output
+    /// equals `self.iter().map(f).collect()`. No more threads are spawned than
+    /// there are elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `threads` - The maximum number of threads to map on.
+    /// * `f` - The transformation applied to each element.
+    ///
+    /// # Returns
+    ///
+    /// A new list with the mapped elements, in the same order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero, or with the panic of `f` if it panics on any thread.
+    #[cfg(feature = "std")]
+    pub fn par_map<U, F>(&self, threads: usize, f: F) -> LinkedList<U>
+    where
+        T: Sync,
+        U: Send,
+        F: Fn(&T) -> U + Sync,
+    {
+        assert!(threads != 0, "par_map: threads must be non-zero");
+        let workers = threads.min(self.length);
+        if workers == 0 {
+            return LinkedList::new();
+        }
+
+        let base = self.length / workers;
+        let extra = self.length % workers;
+        let mut segments = Vec::with_capacity(workers);
+        let mut next = self.head.as_deref();
+        for i in 0..workers {
+            let size = base + usize::from(i < extra);
+            segments.push(Iter { next, remaining: size });
+            for _ in 0..size {
+                next = next.and_then(|node| node.next.as_deref());
+            }
+        }
+        self.stats.traversed(self.length);
+
+        let f = &f;
+        let parts: Vec<LinkedList<U>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = segments.into_iter().map(|segment| scope.spawn(move || segment.map(f).collect())).collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap_or_else(|payload| std::panic::resume_unwind(payload)))
+                .collect()
+        });
+
+        // Join from the back, so every part's tail is found by walking only that part.
+        parts.into_iter().rev().fold(LinkedList::new(), |rest, mut part| {
+            part += rest;
+            part
+        })
+    }
+
+    /// Cuts `chain` after its first `at` nodes and returns the remainder.
+    ///
+    /// If the chain holds `at` nodes or fewer, it is left untouched and `None` is returned.
+    fn split_chain(chain: &mut OptionNode<T>, at: usize) -> OptionNode<T> {
+        Self::chain_link(chain, at).take()
+    }
+
+    /// Returns the link after the first `at` nodes of `chain`, or its final empty link if it is shorter.
+    fn chain_link(chain: &mut OptionNode<T>, at: usize) -> &mut OptionNode<T> {
+        let mut cursor = chain;
+        for _ in 0..at {
+            match cursor {
+                Some(node) => cursor = &mut node.next,
+                None => break,
+            }
+        }
+
+        cursor
+    }
+
+    /// Exchanges the elements in `range` with the elements in the same range of `other`.
+    ///
+    /// The two sub-chains are swapped by relinking, in one traversal of each list, so no
+    /// element is moved or cloned. Both lists keep their lengths.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to exchange the range with.
+    /// * `range` - The positions to exchange, the same in both lists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or extends past the end of either list.
+    pub fn swap_range(&mut self, other: &mut LinkedList<T>, range: Range<usize>) {
+        assert!(
+            range.start <= range.end && range.end <= self.length && range.end <= other.length,
+            "swap_range: range {:?} out of bounds for lists of length {} and {}",
+            range,
+            self.length,
+            other.length
+        );
+        let count = range.end - range.start;
+        if count == 0 {
+            return;
+        }
+
+        let stats = (&self.stats, &other.stats);
+        let self_link = Self::chain_link(&mut self.head, range.start);
+        let other_link = Self::chain_link(&mut other.head, range.start);
+        let mut self_segment = self_link.take();
+        let mut other_segment = other_link.take();
+
+        let self_end = Self::chain_link(&mut self_segment, count);
+        let other_end = Self::chain_link(&mut other_segment, count);
+        std::mem::swap(self_end, other_end);
+
+        *self_link = other_segment;
+        *other_link = self_segment;
+        for stats in [stats.0, stats.1] {
+            stats.traversed(range.end);
+            stats.relinked(1);
+        }
+    }
+
+    /// Removes the expired prefix of a list sorted by deadline and returns it.
+    ///
+    /// The list is assumed to be sorted by `key` in ascending order. Elements are
+    /// removed from the front while their key is less than or equal to `now`, and the
+    /// removed prefix is cut off in one piece, so this takes O(removed) time.
+    /// On an unsorted list, draining simply stops at the first unexpired element.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time; elements whose key equals `now` are expired.
+    /// * `key` - Extracts the deadline of an element.
+    ///
+    /// # Returns
+    ///
+    /// The expired elements, in their original order.
+    ///
+    /// # Panics
+    ///
+    /// The prefix is only cut off after every key has been compared, so if `key`
+    /// panics the list is left unchanged.
+    pub fn drain_expired<K: Ord, F: FnMut(&T) -> K>(&mut self, now: K, mut key: F) -> LinkedList<T> {
+        let mut cursor = &mut self.head;
+        let mut count = 0;
+        while cursor.as_ref().is_some_and(|node| key(&node.data) <= now) {
+            if let Some(node) = cursor {
+                cursor = &mut node.next;
+                count += 1;
+            }
+        }
+
+        let rest = cursor.take();
+        let mut drained = LinkedList::new();
+        drained.head = std::mem::replace(&mut self.head, rest);
+        drained.length = count;
+        shrink(&mut self.length, count);
+        self.stats.traversed(count);
+        self.stats.relinked(usize::from(count != 0));
+
+        drained
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, in their original order.
+    ///
+    /// Each element is visited exactly once, front to back, and rejected nodes are
+    /// unlinked and dropped as they are reached. The length is updated with every
+    /// removal, so if `f` panics the list stays consistent.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Returns `true` for the elements to keep.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, passing each one mutably.
+    ///
+    /// Like [`LinkedList::retain`], but `f` may also modify the elements it keeps,
+    /// as with `Vec::retain_mut`. Each element is visited exactly once and no
+    /// element is cloned.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Updates an element and returns `true` if it should be kept.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let mut cursor = &mut self.head;
+        while cursor.is_some() {
+            let keep = cursor.as_mut().is_some_and(|node| f(&mut node.data));
+            if keep {
+                if let Some(node) = cursor {
+                    cursor = &mut node.next;
+                }
+            } else if let Some(mut node) = cursor.take() {
+                *cursor = node.next.take();
+                shrink(&mut self.length, 1);
+                self.stats.freed(1);
+            }
+        }
+    }
+
+    /// Moves every node whose index satisfies `extract` into a new list, preserving order.
+    fn extract_by_index<F: FnMut(usize) -> bool>(&mut self, mut extract: F) -> LinkedList<T> {
+        let mut extracted = LinkedList::new();
+        let mut extracted_tail = &mut extracted.head;
+        let mut moved = 0;
+
+        let mut cursor = &mut self.head;
+        let mut index = 0;
+        while cursor.is_some() {
+            if extract(index) {
+                if let Some(mut node) = cursor.take() {
+                    *cursor = node.next.take();
+                    extracted_tail = &mut extracted_tail.insert(node).next;
+                    shrink(&mut self.length, 1);
+                    grow(&mut extracted.length, 1, "extract");
+                    moved += 1;
+                }
+            } else if let Some(node) = cursor {
+                cursor = &mut node.next;
+            }
+            index += 1;
+        }
+
+        self.stats.relinked(moved);
+        extracted
+    }
+
+    /// Merges runs of adjacent elements with a closure, in a single pass.
+    ///
+    /// This follows `Itertools::coalesce`: `f` is called on each adjacent pair.
+    /// `Ok(merged)` replaces both elements with `merged`, which is then considered
+    /// against the following element; `Err((a, b))` keeps `a` and continues with `b`.
+    /// Order is preserved and the length decreases by the number of merges. Every
+    /// merge result is written back into the node of the element it started from,
+    /// so nothing is allocated and only the absorbed nodes are freed; this takes
+    /// O(n) time.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Combines two adjacent elements, or hands both back unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the list keeps the elements it had already emitted and the
+    /// unvisited elements are dropped.
+    pub fn coalesce<F: FnMut(T, T) -> Result<T, (T, T)>>(&mut self, mut f: F) {
+        let mut rest = self.head.take();
+        self.length = 0;
+
+        let (mut current, mut slot) = match rest.take() {
+            Some(node) => {
+                let (Node { data, next }, slot) = Self::empty_node(node);
+                rest = next;
+                (data, slot)
+            }
+            None => return,
+        };
+        let mut tail = &mut self.head;
+        while let Some(node) = rest.take() {
+            let (Node { data, next }, following_slot) = Self::empty_node(node);
+            rest = next;
+            match f(current, data) {
+                Ok(merged) => {
+                    current = merged;
+                    drop(following_slot);
+                    self.stats.freed(1);
+                }
+                Err((kept, following)) => {
+                    tail = &mut tail.insert(Box::write(slot, Node::new(kept))).next;
+                    grow(&mut self.length, 1, "coalesce");
+                    current = following;
+                    slot = following_slot;
+                }
+            }
+        }
+        *tail = Some(Box::write(slot, Node::new(current)));
+        grow(&mut self.length, 1, "coalesce");
+    }
+
+    /// Moves the contents out of a boxed node, keeping its allocation for reuse.
+    fn empty_node(node: Box<Node<T>>) -> (Node<T>, Box<MaybeUninit<Node<T>>>) {
+        let raw = Box::into_raw(node);
+        // SAFETY: `raw` comes from a live `Box`, so it is valid for one read that moves
+        // the node out. `MaybeUninit<Node<T>>` has the layout of `Node<T>`, so the
+        // allocation can be owned again as an uninitialized box, which frees it
+        // without dropping the moved-out contents.
+        unsafe { (raw.read(), Box::from_raw(raw.cast::<MaybeUninit<Node<T>>>())) }
+    }
+
+    /// Splits the list into the segments separated by delimiter elements, cloning them.
+    ///
+    /// This follows `str::split`: delimiters are not part of any segment, consecutive
+    /// delimiters produce empty segments, a leading or trailing delimiter produces a
+    /// leading or trailing empty segment, and an empty list yields one empty segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_delim` - Returns `true` for the elements that separate segments.
+    ///
+    /// # Returns
+    ///
+    /// The segments in order; one more than the number of delimiters.
+    pub fn split_by<F: FnMut(&T) -> bool>(&self, mut is_delim: F) -> LinkedList<LinkedList<T>>
+    where
+        T: Clone,
+    {
+        let mut elems = self.iter();
+        let mut finished = false;
+        let mut segments = LinkedList::new();
+        segments.link_at_tail(std::iter::from_fn(|| {
+            if finished {
+                return None;
+            }
+            let mut segment = LinkedList::new();
+            segment.link_at_tail(std::iter::from_fn(|| match elems.next() {
+                Some(elem) if !is_delim(elem) => Some(elem.clone()),
+                Some(_) => None,
+                None => {
+                    finished = true;
+                    None
+                }
+            }));
+            Some(segment)
+        }));
+
+        segments
+    }
+
+    /// Splits the list into the segments separated by delimiter elements, consuming it.
+    ///
+    /// Segments follow the same rules as [`LinkedList::split_by`], but their nodes are
+    /// relinked instead of cloned; the delimiters are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_delim` - Returns `true` for the elements that separate segments.
+    ///
+    /// # Returns
+    ///
+    /// The segments in order; one more than the number of delimiters.
+    pub fn into_split_by<F: FnMut(&T) -> bool>(mut self, mut is_delim: F) -> LinkedList<LinkedList<T>> {
+        let mut rest = self.head.take();
+        self.length = 0;
+        let mut finished = false;
+        let mut segments = LinkedList::new();
+        segments.link_at_tail(std::iter::from_fn(|| {
+            if finished {
+                return None;
+            }
+            let mut segment = LinkedList::new();
+            let mut tail = &mut segment.head;
+            while let Some(mut node) = rest.take() {
+                rest = node.next.take();
+                if is_delim(&node.data) {
+                    return Some(segment);
+                }
+                tail = &mut tail.insert(node).next;
+                grow(&mut segment.length, 1, "into_split_by");
+            }
+            finished = true;
+            Some(segment)
+        }));
+
+        segments
+    }
+
+    /// Splits the list after each delimiter element, consuming it.
+    ///
+    /// This follows `str::split_inclusive`: every segment ends with its delimiter
+    /// except possibly the last, a trailing delimiter does not produce a trailing
+    /// empty segment, and an empty list yields no segments. The nodes are relinked,
+    /// so flattening the segments reproduces the original list.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_delim` - Returns `true` for the elements that end a segment.
+    ///
+    /// # Returns
+    ///
+    /// The non-empty segments in order.
+    pub fn into_split_inclusive_by<F: FnMut(&T) -> bool>(mut self, mut is_delim: F) -> LinkedList<LinkedList<T>> {
+        let mut rest = self.head.take();
+        self.length = 0;
+        let mut segments = LinkedList::new();
+        segments.link_at_tail(std::iter::from_fn(|| {
+            rest.as_ref()?;
+            let mut segment = LinkedList::new();
+            let mut tail = &mut segment.head;
+            while let Some(mut node) = rest.take() {
+                rest = node.next.take();
+                let delim = is_delim(&node.data);
+                tail = &mut tail.insert(node).next;
+                grow(&mut segment.length, 1, "into_split_inclusive_by");
+                if delim {
+                    break;
+                }
+            }
+            Some(segment)
+        }));
+
+        segments
+    }
+
+    /// Provides an entry for the front of the list, for in-place inspection and modification.
+    ///
+    /// # Returns
+    ///
+    /// `Entry::Occupied` if the list has a first element, `Entry::Vacant` otherwise.
+    pub fn front_entry(&mut self) -> Entry<'_, T> {
+        let link = &mut self.head;
+        let length = &mut self.length;
+        let stats = &self.stats;
+        if link.is_some() {
+            Entry::Occupied(OccupiedEntry { link, length, stats, index: 0 })
+        } else {
+            Entry::Vacant(VacantEntry { link, length, stats, index: 0 })
+        }
+    }
+
+    /// Provides an entry for the position `index`, for in-place inspection and modification.
+    ///
+    /// The list is traversed once to reach the position; every operation on the
+    /// returned entry is O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position, at most `len()`.
+    ///
+    /// # Returns
+    ///
+    /// `Entry::Occupied` if `index < len()`, or `Entry::Vacant` for the position just
+    /// past the end, which supports appending an element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len()`.
+    pub fn entry_at(&mut self, index: usize) -> Entry<'_, T> {
+        assert!(index <= self.length, "entry_at: index {} out of range for list of length {}", index, self.length);
+
+        self.stats.traversed(index);
+        let link = Self::chain_link(&mut self.head, index);
+        let length = &mut self.length;
+        let stats = &self.stats;
+        if link.is_some() {
+            Entry::Occupied(OccupiedEntry { link, length, stats, index })
+        } else {
+            Entry::Vacant(VacantEntry { link, length, stats, index })
+        }
+    }
+
+    /// Provides a cursor at the first element of the list.
+    ///
+    /// The cursor moves forward only. It is past the end if the list is empty.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { link: Some(&mut self.head), length: &mut self.length, stats: &self.stats, index: 0 }
+    }
+
+    /// Copies the elements into `buf`, replacing its contents, and returns them as a slice.
+    ///
+    /// The vector's allocation is reused, so calling this repeatedly with the same
+    /// buffer only reallocates when the list outgrows its capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - The vector to refill.
+    ///
+    /// # Returns
+    ///
+    /// The elements of the list, in order, as a slice of `buf`.
+    pub fn copy_into<'a>(&self, buf: &'a mut Vec<T>) -> &'a [T]
+    where
+        T: Clone,
+    {
+        buf.clear();
+        buf.reserve(self.length);
+        buf.extend(self.iter().cloned());
+        buf
+    }
+
+    /// Copies the leading elements into `buf` without allocating.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - The slice to fill from the front.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements copied, which is the smaller of `len()` and `buf.len()`.
+    /// Slots past that count are left untouched.
+    pub fn copy_into_slice(&self, buf: &mut [T]) -> usize
+    where
+        T: Clone,
+    {
+        let mut copied = 0;
+        for (slot, elem) in buf.iter_mut().zip(self.iter()) {
+            slot.clone_from(elem);
+            copied += 1;
+        }
+
+        copied
+    }
+
+    /// Returns the operation counters recorded since the list was created or last reset.
+    ///
+    /// See [`ListStats`](crate::stats::ListStats) for what each counter measures.
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::stats::ListStats {
+        self.stats.snapshot()
+    }
+
+    /// Sets every operation counter back to zero.
+    #[cfg(feature = "stats")]
+    pub fn reset_stats(&mut self) {
+        self.stats.reset();
+    }
+
+    /// Provides an iterator over the list's elements.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that yields references to the elements in the list.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+            remaining: self.length,
+        }
+    }
+
+    /// Provides an iterator that allows modifying each element in place.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that yields mutable references to the elements in the list.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head.as_deref_mut(),
+            remaining: self.length,
+        }
+    }
+
+    /// Removes every element, yielding them by value from front to back.
+    ///
+    /// Unlike [`LinkedList::into_iter`] the list stays usable: once the returned
+    /// iterator is dropped the list is empty, and elements that were not yielded
+    /// are dropped with it.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that moves the elements out of the list.
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { list: self }
+    }
+
+    /// Iterates over clones of the elements while sharing ownership of the list.
+    ///
+    /// The iterator holds its own `Rc` to the list, so it borrows nothing and can be
+    /// stored in `'static` contexts; the list stays alive until both the iterator and
+    /// every other `Rc` are dropped.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that yields a clone of each element, front to back.
+    pub fn iter_owned(self: &Rc<Self>) -> OwnedIter<T>
+    where
+        T: Clone,
+    {
+        OwnedIter {
+            next: self.head.as_deref().map(|node| node as *const Node<T>),
+            remaining: self.length,
+            list: Rc::clone(self),
+        }
+    }
+
+    /// Threads a running state through the elements, front to back, updating both.
+    ///
+    /// `f` receives the state and each element by mutable reference, which is enough
+    /// for prefix sums or delta coding without an intermediate buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial state.
+    /// * `f` - The step applied to the state and each element.
+    ///
+    /// # Returns
+    ///
+    /// The final state, or `init` if the list is empty.
+    pub fn scan_in_place<S, F: FnMut(&mut S, &mut T)>(&mut self, init: S, mut f: F) -> S {
+        let mut state = init;
+        for elem in self.iter_mut() {
+            f(&mut state, elem);
+        }
+        state
+    }
+
+    /// Checks that the recorded length matches the number of linked nodes.
+    ///
+    /// The check is available in every build, not only in tests, so it can guard
+    /// production code paths. It recounts the whole chain and takes O(n) time.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the list is consistent, or `ConsistencyError::LengthMismatch` otherwise.
+    pub fn assert_consistent(&self) -> Result<(), ConsistencyError> {
+        let actual = self.count_links();
+        if actual == self.length {
+            Ok(())
+        } else {
+            Err(ConsistencyError::LengthMismatch { expected: self.length, actual })
+        }
+    }
+
+    /// Counts the linked nodes without trusting `length`, which bounds `iter()`.
+    fn count_links(&self) -> usize {
+        std::iter::successors(self.head.as_deref(), |node| node.next.as_deref()).count()
+    }
+
+    /// Borrows the elements from `index` to the end as a read-only view.
+    ///
+    /// No element is cloned; the view points into this list and borrows it.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the first element of the view, at most `len()`.
+    ///
+    /// # Returns
+    ///
+    /// A view of the suffix, which is empty when `index == len()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len()`.
+    pub fn view_from(&self, index: usize) -> ListView<'_, T> {
+        assert!(index <= self.length, "view_from: index {} out of range for list of length {}", index, self.length);
+
+        let mut head = self.head.as_deref();
+        for _ in 0..index {
+            head = head.and_then(|node| node.next.as_deref());
+        }
+        self.stats.traversed(index);
+        ListView { head, len: self.length - index }
+    }
+}
+
+/// Concatenates a sequence of lists into one by relinking their nodes.
+///
+/// Each input list is walked once, so the total cost is linear in the number of elements.
+///
+/// # Arguments
+///
+/// * `lists` - The lists to be joined, in order.
+///
+/// # Returns
+///
+/// A list holding the elements of every input list, in order.
+pub fn concat<T>(lists: impl IntoIterator<Item = LinkedList<T>>) -> LinkedList<T> {
+    let mut joined = LinkedList::new();
+    let mut length = 0;
+
+    let mut cursor = &mut joined.head;
+    for mut list in lists {
+        grow(&mut length, list.length, "concat");
+        list.length = 0;
+        *cursor = list.head.take();
+        while let Some(node) = cursor {
+            cursor = &mut node.next;
+        }
+    }
+
+    joined.length = length;
+    joined
+}
+
+/// Implementation of the `+` operator, concatenating two lists by relinking.
+impl<T> Add for LinkedList<T> {
+    type Output = LinkedList<T>;
+
+    fn add(mut self, mut rhs: LinkedList<T>) -> Self::Output {
+        self.splice_back(&mut rhs, "add");
+        self
+    }
+}
+
+/// Implementation of the `+=` operator, moving every element of the right-hand list to the end.
+impl<T> AddAssign for LinkedList<T> {
+    fn add_assign(&mut self, mut rhs: LinkedList<T>) {
+        self.splice_back(&mut rhs, "add");
+    }
+}
+
+#[cfg(feature = "std")]
+impl LinkedList<String> {
+    /// Constructs a list of the lines of `reader`, read and appended one at a time.
+    ///
+    /// Lines are split as by [`BufRead::lines`], without their `\n` or `\r\n`
+    /// terminator, and each append is O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of `reader`, including lines that are not valid UTF-8.
+    pub fn from_lines<R: BufRead>(reader: R) -> io::Result<Self> {
+        Self::try_collect(reader.lines())
+    }
+}
+
+/// The error returned by [`LinkedList::from_delimited`], with the failing record's 1-based number.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum FromReadError<E> {
+    /// Reading the record failed, or it is not valid UTF-8.
+    Io {
+        /// The number of the record.
+        line: usize,
+        /// The underlying error.
+        error: io::Error,
+    },
+    /// The parser rejected the record.
+    Parse {
+        /// The number of the record.
+        line: usize,
+        /// The error returned by the parser.
+        error: E,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<E: std::fmt::Display> std::fmt::Display for FromReadError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromReadError::Io { line, error } => write!(f, "failed to read record {}: {}", line, error),
+            FromReadError::Parse { line, error } => write!(f, "failed to parse record {}: {}", line, error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for FromReadError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FromReadError::Io { error, .. } => Some(error),
+            FromReadError::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+/// The error returned by fallible pushes when a node could not be allocated.
+#[cfg(feature = "fallible-alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocError;
+
+#[cfg(feature = "fallible-alloc")]
+impl std::fmt::Display for AllocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "memory allocation failed")
+    }
+}
+
+#[cfg(feature = "fallible-alloc")]
+impl std::error::Error for AllocError {}
+
+#[cfg(all(feature = "fallible-alloc", test))]
+thread_local! {
+    /// Makes the next `try_box` on this thread fail, for testing the failure paths.
+    static FAIL_NEXT_ALLOCATION: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Moves `value` into a new box, handing it back if the allocation fails.
+///
+/// This is a stable stand-in for `Box::try_new`: it allocates through the global
+/// allocator directly and checks for a null pointer instead of aborting.
+#[cfg(feature = "fallible-alloc")]
+fn try_box<T>(value: T) -> Result<Box<T>, T> {
+    let layout = std::alloc::Layout::new::<T>();
+    if layout.size() == 0 {
+        return Ok(Box::new(value));
+    }
+    #[cfg(test)]
+    if FAIL_NEXT_ALLOCATION.with(|fail| fail.replace(false)) {
+        return Err(value);
+    }
+
+    // SAFETY: the layout has a non-zero size, as `alloc` requires.
+    let ptr = unsafe { std::alloc::alloc(layout) }.cast::<T>();
+    if ptr.is_null() {
+        return Err(value);
+    }
+    // SAFETY: `ptr` is a fresh allocation with the layout of `T`, which is exactly
+    // what `Box::from_raw` expects for memory obtained from the global allocator.
+    unsafe {
+        ptr.write(value);
+        Ok(Box::from_raw(ptr))
+    }
+}
+
+/// Increases `length` by `count`, panicking with the name of `operation` on overflow.
+fn grow(length: &mut usize, count: usize, operation: &str) {
+    *length = match length.checked_add(count) {
+        Some(grown) => grown,
+        None => panic!("{}: list length overflowed usize", operation),
+    };
+}
+
+/// Decreases `length` by `count`, which must not exceed the current length.
+fn shrink(length: &mut usize, count: usize) {
+    debug_assert!(*length >= count, "list length accounting underflow");
+    *length -= count;
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implementation of Drop trait so that dropping a long list doesn't recurse through every node.
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Implementation of the Clone trait, producing an independent deep copy.
+///
+/// The nodes are copied in a single loop rather than by recursing through the
+/// `next` links, so cloning a long list cannot overflow the stack.
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        let mut copy = LinkedList::new();
+        copy.link_at_tail(self.iter().cloned());
+        copy
+    }
+}
+
+/// Implementation of the Index trait, so elements can be read with `list[index]`.
+///
+/// Each access follows `index` links from the head and takes O(index) time, so
+/// iterate instead of indexing in a loop.
+///
+/// # Panics
+///
+/// Panics if `index >= len()`; use [`LinkedList::get`] to get an `Option` instead.
+impl<T> Index<usize> for LinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(elem) => elem,
+            None => panic!("index: index {} out of range for list of length {}", index, self.length),
+        }
+    }
+}
+
+/// Implementation of the IndexMut trait, so elements can be written with `list[index]`.
+///
+/// Like [`Index`], each access takes O(index) time.
+///
+/// # Panics
+///
+/// Panics if `index >= len()`; use [`LinkedList::get_mut`] to get an `Option` instead.
+impl<T> IndexMut<usize> for LinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let length = self.length;
+        match self.get_mut(index) {
+            Some(elem) => elem,
+            None => panic!("index_mut: index {} out of range for list of length {}", index, length),
+        }
+    }
+}
+
+/// Implementation of the PartialEq trait, comparing the elements in order.
+///
+/// Lists of different lengths are told apart from the stored lengths without
+/// walking either list.
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+/// Implementation of the Hash trait, consistent with PartialEq.
+///
+/// The length is hashed before the elements, so that lists whose elements merely
+/// concatenate to the same sequence, such as `["ab"]` and `["a", "b"]`, hash differently.
+impl<T: std::hash::Hash> std::hash::Hash for LinkedList<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.length);
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
+/// Implementation of the PartialOrd trait, comparing the elements lexicographically.
+///
+/// A list that is a prefix of another compares less, as with `Vec`.
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+/// Implementation of the Ord trait, comparing the elements lexicographically.
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Implementation of Debug trait to enable printing of the list for debugging purposes.
+///
+/// At most [`crate::DEBUG_LIMIT`] elements are printed unless a precision (`{:.N?}`)
+/// or the alternate flag (`{:#?}`) is given.
+impl<T> std::fmt::Debug for LinkedList<T> where T: std::fmt::Debug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let limit = if f.alternate() { usize::MAX } else { f.precision().unwrap_or(crate::DEBUG_LIMIT) };
+
+        let mut current = &self.head;
+        let mut shown = 0;
+        while let Some(node) = current {
+            if shown == limit {
+                write!(f, ".. {} more -> ", self.length - shown)?;
+                break;
+            }
+            write!(f, "{:?} -> ", node.data)?;
+            current = &node.next;
+            shown += 1;
+        }
+        write!(f, "End")
+    }
+}
+
+/// Implementation of the Display trait for user-facing output.
+///
+/// The elements are separated by `", "`, with no end marker; use
+/// [`LinkedList::join`] for a different separator.
+impl<T: std::fmt::Display> std::fmt::Display for LinkedList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_joined(f, ", ")
+    }
+}
+
+/// Iterator over the elements of a `LinkedList`.
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+    /// Elements left to yield; the iteration ends when this reaches zero.
+    remaining: usize,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { next: self.next, remaining: self.remaining }
+    }
+}
+
+/// Implementation of the Debug trait, listing the elements not yet yielded.
+impl<T: std::fmt::Debug> std::fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Iter")?;
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Borrows the elements not yet yielded as a read-only view.
+    ///
+    /// The view borrows the list rather than the iterator, so it stays usable after
+    /// the iterator is advanced further or dropped.
+    ///
+    /// # Returns
+    ///
+    /// A view of the remaining elements, in order.
+    pub fn remainder(&self) -> ListView<'a, T> {
+        ListView { head: self.next, len: self.remaining }
+    }
+}
+
+/// Implementation of the Iterator trait for Iter.
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    /// Folds every remaining element, loading each successor before running `f`.
+    ///
+    /// Reading the next link ahead of the closure lets the load of the following node
+    /// overlap with the work on the current one, which matters for long lists whose
+    /// nodes are scattered across memory. The result is identical to the default `fold`.
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        let mut current = self.next;
+        let mut remaining = self.remaining;
+        while let Some(node) = current.filter(|_| remaining > 0) {
+            current = node.next.as_deref();
+            remaining -= 1;
+            acc = f(acc, &node.data);
+        }
+
+        acc
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Mutable iterator over the elements of a `LinkedList`.
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+    /// Elements left to yield.
+    remaining: usize,
+}
+
+/// Implementation of the Debug trait, listing the elements not yet yielded.
+impl<T: std::fmt::Debug> std::fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nodes = std::iter::successors(self.next.as_deref(), |node| node.next.as_deref());
+        f.write_str("IterMut")?;
+        f.debug_list().entries(nodes.map(|node| &node.data)).finish()
+    }
+}
+
+/// Implementation of the Iterator trait for IterMut.
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            self.remaining -= 1;
+            &mut node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// A read-only view of a suffix of a `LinkedList`.
+///
+/// Created by [`LinkedList::view_from`] or [`Iter::remainder`]. The view borrows the
+/// list, so the list cannot be modified while it is alive:
+///
+/// ```compile_fail
+/// use linked_list::single::LinkedList;
+///
+/// let mut list = LinkedList::new();
+/// list.push_front(1);
+/// let view = list.view_from(0);
+/// list.push_front(2);
+/// assert_eq!(view, [1]);
+/// ```
+pub struct ListView<'a, T> {
+    head: Option<&'a Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> ListView<'a, T> {
+    /// Provides an iterator over the elements of the view.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that yields references to the elements, in order.
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter { next: self.head, remaining: self.len }
+    }
+
+    /// Returns the number of elements in the view.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the view holds no elements.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the view is empty, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Provides a reference to the first element of the view.
+    ///
+    /// # Returns
+    ///
+    /// The first element, or `None` if the view is empty.
+    pub fn front(&self) -> Option<&'a T> {
+        self.head.map(|node| &node.data)
+    }
+}
+
+impl<T> Clone for ListView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListView<'_, T> {}
+
+/// Implementation of the IntoIterator trait for ListView.
+impl<'a, T> IntoIterator for ListView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Implementation of the PartialEq trait for comparing a ListView with a slice.
+impl<T: PartialEq> PartialEq<[T]> for ListView<'_, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+/// Implementation of the PartialEq trait for comparing a ListView with an array.
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for ListView<'_, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+/// Implementation of the Debug trait for ListView, listing the viewed elements.
+impl<T: std::fmt::Debug> std::fmt::Debug for ListView<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An owning iterator over the elements of a `LinkedList`.
+///
+/// Created by [`LinkedList::into_iter`]. Elements not yet yielded are dropped along
+/// with the iterator.
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+}
+
+/// Implementation of the Iterator trait for IntoIter.
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// Implementation of the Debug trait, listing the elements not yet yielded.
+impl<T: std::fmt::Debug> std::fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("IntoIter")?;
+        f.debug_list().entries(self.list.iter()).finish()
+    }
+}
+
+/// Implementation of the IntoIterator trait for LinkedList, yielding elements by value.
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+/// A draining iterator over the elements of a `LinkedList`.
+///
+/// Created by [`LinkedList::drain`]. Dropping it drops the elements not yet
+/// yielded and leaves the list empty.
+pub struct Drain<'a, T> {
+    list: &'a mut LinkedList<T>,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        self.list.clear();
+    }
+}
+
+/// An iterator over clones of the elements of a shared `LinkedList`.
+///
+/// Created by [`LinkedList::iter_owned`]. It keeps the list alive through its own
+/// `Rc`, so it has no lifetime parameter.
+pub struct OwnedIter<T> {
+    list: Rc<LinkedList<T>>,
+    next: Option<*const Node<T>>,
+    remaining: usize,
+}
+
+impl<T: Clone> Iterator for OwnedIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: `next` points into a node owned by `self.list`. The `Rc` held here
+        // keeps the list alive, and while it is shared the list can only be reached
+        // through shared references, so no node is moved, freed or relinked.
+        let node = unsafe { &*self.next? };
+        self.next = node.next.as_deref().map(|next| next as *const Node<T>);
+        self.remaining -= 1;
+        Some(node.data.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for OwnedIter<T> {}
+
+impl<T> Clone for OwnedIter<T> {
+    fn clone(&self) -> Self {
+        OwnedIter { list: Rc::clone(&self.list), next: self.next, remaining: self.remaining }
+    }
+}
+
+/// Implementation of the FromIterator trait, so a list can be built with `collect()`.
+///
+/// The first item becomes the head. Nodes are linked in a single pass, so this takes
+/// O(n) time.
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        list.link_at_tail(iter.into_iter());
+        list
+    }
+}
+
+/// Implementation of the From trait, moving the elements of a vector into a list in order.
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+/// Implementation of the From trait, moving the elements of an array into a list in order.
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(array: [T; N]) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+/// Implementation of the From trait, cloning the elements of a slice into a list in order.
+impl<T: Clone> From<&[T]> for LinkedList<T> {
+    fn from(slice: &[T]) -> Self {
+        slice.iter().cloned().collect()
+    }
+}
+
+/// Implementation of the From trait, moving the elements of a list into a vector in order.
+///
+/// The vector is allocated once with the list's length.
+impl<T> From<LinkedList<T>> for Vec<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        let mut vec = Vec::with_capacity(list.len());
+        vec.extend(list);
+        vec
+    }
+}
+
+/// Implementation of the Extend trait, appending the items at the tail in order.
+///
+/// The tail is located once and the items are linked in a single pass, so this
+/// takes O(n + m) time.
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.link_at_tail(iter.into_iter());
+    }
+}
+
+/// Implementation of the Extend trait for references to `Copy` elements.
+impl<'a, T: Copy + 'a> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.link_at_tail(iter.into_iter().copied());
+    }
+}
+
+/// Implementation of the IntoIterator trait for a borrowed LinkedList.
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Implementation of the IntoIterator trait for a mutably borrowed LinkedList.
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A view into a position of a `LinkedList`, which is either occupied or vacant.
+pub enum Entry<'a, T> {
+    /// The position holds an element.
+    Occupied(OccupiedEntry<'a, T>),
+    /// The position is past the end of the list.
+    Vacant(VacantEntry<'a, T>),
+}
+
+/// A view into an element of a `LinkedList`.
+pub struct OccupiedEntry<'a, T> {
+    link: &'a mut OptionNode<T>,
+    length: &'a mut usize,
+    stats: &'a Counters,
+    index: usize,
+}
+
+impl<'a, T> OccupiedEntry<'a, T> {
+    /// Returns the position of the element in the list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Provides a reference to the element.
+    pub fn get(&self) -> &T {
+        match self.link.as_deref() {
+            Some(node) => &node.data,
+            None => unreachable!("occupied entry without a node"),
+        }
+    }
+
+    /// Provides a mutable reference to the element.
+    pub fn get_mut(&mut self) -> &mut T {
+        match self.link {
+            Some(node) => &mut node.data,
+            None => unreachable!("occupied entry without a node"),
+        }
+    }
+
+    /// Converts the entry into a mutable reference to the element, bound to the list's lifetime.
+    pub fn into_mut(self) -> &'a mut T {
+        match self.link {
+            Some(node) => &mut node.data,
+            None => unreachable!("occupied entry without a node"),
+        }
+    }
+
+    /// Removes the element from the list and returns it.
+    pub fn remove(self) -> T {
+        let node = match self.link.take() {
+            Some(node) => node,
+            None => unreachable!("occupied entry without a node"),
+        };
+        *self.link = node.next;
+        shrink(self.length, 1);
+        self.stats.freed(1);
+
+        node.data
+    }
+
+    /// Inserts an element just before this one.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The element to be inserted.
+    ///
+    /// # Returns
+    ///
+    /// The entry, still referring to the same element.
+    pub fn insert_before(self, elem: T) -> OccupiedEntry<'a, T> {
+        grow(self.length, 1, "insert_before");
+        self.stats.allocated(1);
+        let next = self.link.take();
+        let node = self.link.insert(Box::new(Node { data: elem, next }));
+
+        OccupiedEntry { link: &mut node.next, length: self.length, stats: self.stats, index: self.index + 1 }
+    }
+
+    /// Inserts an element just after this one; the entry keeps referring to the same element.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The element to be inserted.
+    pub fn insert_after(&mut self, elem: T) {
+        grow(self.length, 1, "insert_after");
+        self.stats.allocated(1);
+        match self.link {
+            Some(node) => {
+                let next = node.next.take();
+                node.next = Some(Box::new(Node { data: elem, next }));
+            }
+            None => unreachable!("occupied entry without a node"),
+        }
+    }
+}
+
+/// A view into the position just past the end of a `LinkedList`.
+pub struct VacantEntry<'a, T> {
+    link: &'a mut OptionNode<T>,
+    length: &'a mut usize,
+    stats: &'a Counters,
+    index: usize,
+}
+
+impl<'a, T> VacantEntry<'a, T> {
+    /// Returns the position an inserted element would take, which is the list's length.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Inserts an element at this position.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The element to be inserted.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the inserted element.
+    pub fn insert(self, elem: T) -> &'a mut T {
+        grow(self.length, 1, "insert");
+        self.stats.allocated(1);
+        &mut self.link.insert(Box::new(Node::new(elem))).data
+    }
+}
+
+/// A cursor over a `LinkedList` that moves forward and can remove elements.
+///
+/// Created by [`LinkedList::cursor_front_mut`]. Once it has moved past the last
+/// element the cursor stays at the end.
+pub struct CursorMut<'a, T> {
+    /// The link holding the element under the cursor; only `None` while it moves.
+    link: Option<&'a mut OptionNode<T>>,
+    length: &'a mut usize,
+    stats: &'a Counters,
+    index: usize,
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Returns the position of the cursor, which is `len()` past the end.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a mutable reference to the element under the cursor, if any.
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.link.as_deref_mut()?.as_deref_mut().map(|node| &mut node.data)
+    }
+
+    /// Returns the element under the cursor and the one after it.
+    ///
+    /// Either is `None` where the list ends.
+    pub fn peek_two(&self) -> (Option<&T>, Option<&T>) {
+        let current = self.link.as_deref().and_then(|link| link.as_deref());
+        let next = current.and_then(|node| node.next.as_deref());
+        (current.map(|node| &node.data), next.map(|node| &node.data))
+    }
+
+    /// Moves the cursor to the next element; does nothing past the end.
+    pub fn move_next(&mut self) {
+        self.link = match self.link.take() {
+            Some(Some(node)) => {
+                self.index += 1;
+                Some(&mut node.next)
+            }
+            link => link,
+        };
+    }
+
+    /// Moves the cursor forward to `index`, or to the last element if `index` is past the end.
+    ///
+    /// This is the cursor counterpart of [`LinkedList::get_clamped`]: unlike stepping
+    /// with [`CursorMut::move_next`], it never leaves the cursor past the end of a
+    /// non-empty list. The cursor only moves forward, so a target behind it leaves
+    /// it where it is, and on an empty list it stays at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The requested position.
+    pub fn seek_to_clamped(&mut self, index: usize) {
+        if let Some(last) = self.length.checked_sub(1) {
+            while self.index < index.min(last) {
+                self.move_next();
+            }
+        }
+    }
+
+    /// Removes the element under the cursor if `pred` accepts it.
+    ///
+    /// On removal the cursor moves on to the element that followed.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - Decides whether the current element is consumed.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, or `None` if the cursor is past the end or `pred`
+    /// rejected the element, in which case the cursor does not move.
+    pub fn consume_if<P: FnOnce(&T) -> bool>(&mut self, pred: P) -> Option<T> {
+        let link = self.link.as_deref_mut()?;
+        if !pred(&link.as_deref()?.data) {
+            return None;
+        }
+        let node = link.take()?;
+        *link = node.next;
+        shrink(self.length, 1);
+        self.stats.freed(1);
+        Some(node.data)
+    }
+}
+
+/// A singly linked list that stores its first element inline.
+///
+/// Lists that mostly hold zero or one element never allocate: the front element
+/// lives in the struct itself, and only the elements behind it are boxed in a
+/// regular [`LinkedList`]. The inline slot is empty only when the whole list is.
+pub struct SmallList<T> {
+    /// The front element, if the list is not empty.
+    first: Option<T>,
+    /// The elements after the front one.
+    rest: LinkedList<T>,
+}
+
+impl<T> SmallList<T> {
+    /// Constructs a new, empty SmallList.
+    pub fn new() -> Self {
+        SmallList { first: None, rest: LinkedList::new() }
+    }
+
+    /// Inserts an element at the start of the list.
+    ///
+    /// The previous front element, if any, moves from the inline slot into a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The element to be added to the list.
+    pub fn push_front(&mut self, elem: T) {
+        if let Some(previous) = self.first.replace(elem) {
+            self.rest.push_front(previous);
+        }
+    }
+
+    /// Adds an element to the end of the list.
+    ///
+    /// The element is stored inline if the list is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `elem` - The element to be added to the list.
+    pub fn push_back(&mut self, elem: T) {
+        if self.first.is_none() {
+            self.first = Some(elem);
+        } else {
+            self.rest.push_back(elem);
+        }
+    }
+
+    /// Removes and returns the first element of the list, if it exists.
+    ///
+    /// The next element, if any, moves out of its node into the inline slot.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, if the list was not empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let front = self.first.take()?;
+        self.first = self.rest.pop_front();
+        Some(front)
+    }
+
+    /// Removes and returns the last element of the list, if it exists.
+    ///
+    /// # Returns
+    ///
+    /// The removed element, if the list was not empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.rest.pop_back().or_else(|| self.first.take())
+    }
+
+    /// Provides an iterator over the list's elements.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that yields the inline element, then the boxed ones.
+    pub fn iter(&self) -> std::iter::Chain<std::option::Iter<'_, T>, Iter<'_, T>> {
+        self.first.iter().chain(self.rest.iter())
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        usize::from(self.first.is_some()) + self.rest.len()
+    }
+
+    /// Checks if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.first.is_none()
+    }
+
+    /// Removes every element from the list.
+    pub fn clear(&mut self) {
+        self.first = None;
+        self.rest.clear();
+    }
+
+    /// Returns the operation counters of the boxed part of the list.
+    ///
+    /// The inline element is never allocated, so it does not show up here.
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::stats::ListStats {
+        self.rest.stats()
+    }
+}
+
+impl<T> Default for SmallList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test the creation of a new LinkedList.
+    #[test]
+    fn test_new() {
+        let list: LinkedList<i32> = LinkedList::new();
+        assert!(list.is_empty());
+
+        assert_consistent(&list);
+    }
+
+    // Test pushing elements onto the front of the list.
+    #[test]
+    fn test_push_front() {
+        let mut list = LinkedList::new();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{:?}", list), "3 -> 2 -> 1 -> End");
+
+        assert_consistent(&list);
+    }
+
+    // Test popping elements from the front of the list.
+    #[test]
+    fn test_pop_front() {
+        let mut list = LinkedList::new();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), None);
+
+        assert_consistent(&list);
+    }
+
+    // Test appending elements to the end of the list.
+    #[test]
+    fn test_push_back() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{:?}", list), "1 -> 2 -> 3 -> End");
+
+        assert_consistent(&list);
+    }
+
+    // Test popping elements from the back of the list.
+    #[test]
+    fn test_pop_back() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+
+        assert_consistent(&list);
+    }
+
+    // Test that the deprecated names still forward to their replacements.
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_names() {
+        let mut list = LinkedList::new();
+        list.push(2);
+        list.push(1);
+        list.append(3);
+
+        assert_eq!(format!("{:?}", list), "1 -> 2 -> 3 -> End");
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.len(), 2);
+
+        assert_consistent(&list);
+    }
+
+    // Test clearing the list.
+    #[test]
+    fn test_clear() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.clear();
+
+        assert!(list.is_empty());
+
+        assert_consistent(&list);
+    }
+
+    // Test iterating over the list.
+    #[test]
+    fn test_iter() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        assert_consistent(&list);
+    }
+
+    // Test the length of the list after operations.
+    #[test]
+    fn test_length_after_operations() {
+        let mut list = LinkedList::new();
+
+        list.push_front(1);
+        list.push_back(2);
+        assert_eq!(list.len(), 2);
+
+        list.pop_front();
+        assert_eq!(list.len(), 1);
+
+        assert_consistent(&list);
+    }
+
+    // Test searching a list of strings with string slices.
+    #[test]
+    fn test_search_strings_with_str() {
+        let mut list = LinkedList::new();
+        list.push_back(String::from("a"));
+        list.push_back(String::from("b"));
+        list.push_back(String::from("a"));
+
+        assert!(list.contains("b"));
+        assert!(!list.contains("c"));
+        assert_eq!(list.count_of("a"), 2);
+        assert_eq!(list.find_seq(["b", "a"]), Some(1));
+        assert_eq!(list.remove_first("a"), Some(String::from("a")));
+        assert_eq!(list.find_seq(["b", "a"]), Some(0));
+        assert_eq!(list.len(), 2);
+
+        assert_consistent(&list);
+    }
+
+    // Test searching a list of byte vectors with byte slices.
+    #[test]
+    fn test_search_bytes_with_slice() {
+        let mut list = LinkedList::new();
+        list.push_back(vec![1u8, 2]);
+        list.push_back(vec![3u8]);
+
+        assert!(list.contains(&[3u8][..]));
+        assert_eq!(list.count_of(&[1u8, 2][..]), 1);
+        assert_eq!(list.find_seq([&[3u8][..]]), Some(1));
+        assert_eq!(list.remove_first(&[1u8, 2][..]), Some(vec![1, 2]));
+        assert_eq!(list.len(), 1);
+
+        assert_consistent(&list);
+    }
+
+    // Test searching a list of integers by reference.
+    #[test]
+    fn test_search_integers() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+        assert_eq!(list.count_of(&2), 2);
+        assert_eq!(list.find_seq(&[2, 3]), Some(3));
+        assert_eq!(list.find_seq(&[3, 4]), None);
+        assert_eq!(list.find_seq(&[]), Some(0));
+
+        assert_consistent(&list);
+    }
+
+    // Test removing the first matching element.
+    #[test]
+    fn test_remove_first() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(2);
+
+        assert_eq!(list.remove_first(&2), Some(2));
+        assert_eq!(list.remove_first(&5), None);
+        assert_eq!(format!("{:?}", list), "1 -> 3 -> 2 -> End");
+        assert_eq!(list.remove_first(&1), Some(1));
+        assert_eq!(list.remove_first(&2), Some(2));
+        assert_eq!(list.len(), 1);
+        assert_eq!(format!("{:?}", list), "3 -> End");
+
+        assert_consistent(&list);
+    }
+
+    // Test that the bulk-construction path builds the same list as repeated appends.
+    #[test]
+    fn test_extend_fast_matches_append() {
+        let mut slow = LinkedList::new();
+        let mut fast = LinkedList::new();
+        for i in 0..100 {
+            slow.push_back(i);
+        }
+        fast.extend_fast(0..100);
+
+        assert_eq!(fast.len(), slow.len());
+        assert!(fast.iter().eq(slow.iter()));
+
+        fast.extend_fast(100..110);
+        for i in 100..110 {
+            slow.push_back(i);
+        }
+        assert_eq!(fast.len(), 110);
+        assert_eq!(format!("{:?}", fast), format!("{:?}", slow));
+
+        fast.extend_fast(std::iter::empty());
+        assert_eq!(fast.len(), 110);
+    }
+
+    // Test that dropping a long list doesn't overflow the stack.
+    #[test]
+    fn test_drop_long_list() {
+        let mut list = LinkedList::new();
+        list.extend_fast(0..200_000);
+        assert_eq!(list.len(), 200_000);
+        assert_consistent(&list);
+        drop(list);
+    }
+
+    // Test building a very large list through the fast path in release mode.
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn test_extend_fast_large() {
+        let mut list = LinkedList::new();
+        list.extend_fast(0..10_000_000u32);
+
+        assert_eq!(list.len(), 10_000_000);
+        assert_eq!(list.iter().last(), Some(&9_999_999));
+
+        assert_consistent(&list);
+    }
+
+    /// Payload that counts how many times it has been dropped.
+    struct DropCounter {
+        value: i32,
+        drops: std::rc::Rc<std::cell::Cell<usize>>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    impl PartialEq for DropCounter {
+        fn eq(&self, other: &Self) -> bool {
+            self.value == other.value
+        }
+    }
+
+    impl Eq for DropCounter {}
+
+    impl PartialOrd for DropCounter {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for DropCounter {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.value.cmp(&other.value)
+        }
+    }
+
+    // Test sorting into a vector and heapifying, compared against the equivalent Vec.
+    #[test]
+    fn test_into_sorted_vec_and_heap() {
+        for elems in [vec![], vec![3], vec![5, 1, 4, 1, 5, 9, 2, 6, 5, 3], vec![2, 2, 2]] {
+            let mut expected = elems.clone();
+            expected.sort();
+
+            let mut list = LinkedList::new();
+            list.extend_fast(elems.iter().copied());
+            assert_eq!(list.into_sorted_vec(), expected);
+
+            let mut list = LinkedList::new();
+            list.extend_fast(elems.iter().copied());
+            assert_eq!(list.into_binary_heap().into_sorted_vec(), expected);
+        }
+    }
+
+    // Test that sorting and heapifying move every element without dropping any.
+    #[test]
+    fn test_into_sorted_vec_drops_nothing() {
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut list = LinkedList::new();
+        for value in [3, 1, 2] {
+            list.push_front(DropCounter { value, drops: drops.clone() });
+        }
+
+        let sorted = list.into_sorted_vec();
+        assert_eq!(drops.get(), 0);
+        assert_eq!(sorted.iter().map(|elem| elem.value).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let mut list = LinkedList::new();
+        list.extend_fast(sorted.into_iter());
+        assert_consistent(&list);
+        let heap = list.into_binary_heap();
+        assert_eq!(drops.get(), 0);
+        assert_eq!(heap.peek().map(|elem| elem.value), Some(3));
+        drop(heap);
+        assert_eq!(drops.get(), 3);
+    }
+
+    // Test converting every element successfully.
+    #[test]
+    fn test_try_map_all_ok() {
+        let mut list = LinkedList::new();
+        list.push_back("1");
+        list.push_back("2");
+        list.push_back("3");
+
+        assert_consistent(&list);
+        let mapped = list.try_map(|s| s.parse::<i32>()).ok().unwrap();
+        assert_eq!(mapped.len(), 3);
+        assert_eq!(format!("{:?}", mapped), "1 -> 2 -> 3 -> End");
+    }
+
+    // Test that conversion stops at the first error and reports its index.
+    #[test]
+    fn test_try_map_errors() {
+        let mut list = LinkedList::new();
+        list.push_back("x");
+        list.push_back("2");
+        assert_eq!(list.try_map(|s| s.parse::<i32>()).err().map(|(index, _)| index), Some(0));
+
+        let mut list = LinkedList::new();
+        list.push_back("1");
+        list.push_back("x");
+        list.push_back("3");
+        assert_consistent(&list);
+        assert_eq!(list.try_map(|s| s.parse::<i32>()).err().map(|(index, _)| index), Some(1));
+    }
+
+    // Test that the converted prefix and unconverted suffix are dropped exactly once.
+    #[test]
+    fn test_try_map_drop_accounting() {
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut list = LinkedList::new();
+        for value in 0..5 {
+            list.push_back(DropCounter { value, drops: drops.clone() });
+        }
+
+        let converted = std::rc::Rc::new(std::cell::Cell::new(0));
+        assert_consistent(&list);
+        let result = list.try_map(|elem| {
+            if elem.value == 2 {
+                return Err("two");
+            }
+            Ok(DropCounter { value: elem.value * 10, drops: converted.clone() })
+        });
+
+        assert_eq!(result.err(), Some((2, "two")));
+        assert_eq!(drops.get(), 5);
+        assert_eq!(converted.get(), 2);
+    }
+
+    // Test describing the difference between two lists.
+    #[test]
+    fn test_diff() {
+        let mut left = LinkedList::new();
+        left.extend_fast(0..4);
+        let mut right = LinkedList::new();
+        right.extend_fast(0..4);
+
+        assert!(left.diff(&right).is_empty());
+        crate::assert_list_eq!(left, right);
+
+        right.pop_back();
+        right.push_back(9);
+        assert_eq!(left.diff(&right).to_string(), "lists differ at index 3: left = 3, right = 9");
+
+        right.push_back(10);
+        let diff = left.diff(&right);
+        assert_eq!((diff.index, diff.left_len, diff.right_len), (Some(3), 4, 5));
+    }
+
+    // Test that the assertion macro reports the difference.
+    #[test]
+    #[should_panic(expected = "lists differ at index 0: left = 1, right = 2")]
+    fn test_assert_list_eq_fails() {
+        let mut left = LinkedList::new();
+        left.push_front(1);
+        let mut right = LinkedList::new();
+        right.push_front(2);
+
+        crate::assert_list_eq!(left, right);
+    }
+
+    // Test repeating the whole list several times.
+    #[test]
+    fn test_repeat_list() {
+        let mut list = LinkedList::new();
+        list.extend_fast(1..4);
+
+        let none = list.repeat_list(0);
+        assert!(none.is_empty());
+
+        let once = list.repeat_list(1);
+        assert_eq!(once.len(), 3);
+        assert_eq!(format!("{:?}", once), "1 -> 2 -> 3 -> End");
+
+        let thrice = list.repeat_list(3);
+        assert_eq!(thrice.len(), 9);
+        assert_eq!(format!("{:?}", thrice), "1 -> 2 -> 3 -> 1 -> 2 -> 3 -> 1 -> 2 -> 3 -> End");
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert!(empty.repeat_list(5).is_empty());
+
+        assert_consistent(&list);
+    }
+
+    // Test the cycling iterator taken to a bounded count.
+    #[test]
+    fn test_iter_cycle() {
+        let mut list = LinkedList::new();
+        list.extend_fast(1..4);
+
+        let cycled: Vec<_> = list.iter_cycle().take(7).copied().collect();
+        assert_eq!(cycled, vec![1, 2, 3, 1, 2, 3, 1]);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.iter_cycle().next(), None);
+
+        assert_consistent(&list);
+    }
+
+    // Test decimation against the equivalent index-filtered vectors.
+    #[test]
+    fn test_every_nth_matches_vec() {
+        for len in [0, 1, 5, 9, 10, 13] {
+            for n in [1, 2, 3, 4, 7] {
+                let expected_kept: Vec<_> = (0..len).filter(|i| i % n == 0).collect();
+                let expected_rest: Vec<_> = (0..len).filter(|i| i % n != 0).collect();
+
+                let mut kept = LinkedList::new();
+                kept.extend_fast(0..len);
+                kept.keep_every_nth(n);
+                assert_eq!(kept.iter().copied().collect::<Vec<_>>(), expected_kept);
+                assert_eq!(kept.len(), expected_kept.len());
+
+                let mut rest = LinkedList::new();
+                rest.extend_fast(0..len);
+                let removed = rest.remove_every_nth(n);
+                assert_eq!(removed.iter().copied().collect::<Vec<_>>(), expected_kept);
+                assert_eq!(removed.len(), expected_kept.len());
+                assert_eq!(rest.iter().copied().collect::<Vec<_>>(), expected_rest);
+                assert_eq!(rest.len(), expected_rest.len());
+            }
+        }
+    }
+
+    // Test that a zero step is rejected.
+    #[test]
+    #[should_panic(expected = "n must be non-zero")]
+    fn test_keep_every_nth_zero() {
+        let mut list = LinkedList::new();
+        list.push_front(1);
+        list.keep_every_nth(0);
+    }
+
+    // Test mutable access to the last element.
+    #[test]
+    fn test_last_mut() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.last_mut(), None);
+
+        list.extend_fast(1..4);
+        if let Some(last) = list.last_mut() {
+            *last *= 10;
+        }
+
+        assert_eq!(list.pop_back(), Some(30));
+        assert_eq!(list.last_mut(), Some(&mut 2));
+
+        assert_consistent(&list);
+    }
+
+    // Test retrieving both ends of the list in one call.
+    #[test]
+    fn test_first_last() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.first_last(), None);
+
+        list.push_front(7);
+        let (first, last) = list.first_last().unwrap();
+        assert!(std::ptr::eq(first, last));
+
+        list.push_back(8);
+        list.push_back(9);
+        assert_eq!(list.first_last(), Some((&7, &9)));
+
+        assert_consistent(&list);
+    }
+
+    // Test random operation sequences against a `VecDeque` oracle, checking the length after every step.
+    #[test]
+    fn test_model_against_vec_deque() {
+        let mut rng = crate::testing::XorShift::new(0x5eed);
+        for _ in 0..20 {
+            let mut list = LinkedList::new();
+            let mut oracle = std::collections::VecDeque::new();
+
+            for _ in 0..200 {
+                let value = rng.below(16) as i32;
+                match rng.below(8) {
+                    0 => {
+                        list.push_front(value);
+                        oracle.push_front(value);
+                    }
+                    1 => {
+                        list.push_back(value);
+                        oracle.push_back(value);
+                    }
+                    2 => assert_eq!(list.pop_front(), oracle.pop_front()),
+                    3 => assert_eq!(list.pop_back(), oracle.pop_back()),
+                    4 => {
+                        let expected = oracle.iter().position(|x| *x == value).and_then(|i| oracle.remove(i));
+                        assert_eq!(list.remove_first(&value), expected);
+                    }
+                    5 => {
+                        let extra = rng.below(4) as i32;
+                        list.extend_fast(0..extra);
+                        oracle.extend(0..extra);
+                    }
+                    6 => {
+                        let n = rng.below(3) + 1;
+                        list.keep_every_nth(n);
+                        oracle = oracle.into_iter().step_by(n).collect();
+                    }
+                    _ => {
+                        if rng.below(10) == 0 {
+                            list.clear();
+                            oracle.clear();
+                        }
+                    }
+                }
+
+                assert_eq!(list.len(), oracle.len());
+                assert!(list.iter().eq(oracle.iter()));
+            }
+        }
+    }
+
+    // Test dividing a list into parts of nearly equal length.
+    #[test]
+    fn test_split_into() {
+        let lengths = |parts: &[LinkedList<i32>]| parts.iter().map(LinkedList::len).collect::<Vec<_>>();
+
+        let mut list = LinkedList::new();
+        list.extend_fast(0..9);
+        assert_eq!(lengths(&list.split_into(3)), vec![3, 3, 3]);
+
+        let mut list = LinkedList::new();
+        list.extend_fast(0..11);
+        let parts = list.split_into(4);
+        assert_eq!(lengths(&parts), vec![3, 3, 3, 2]);
+        assert_eq!(format!("{:?}", parts[1]), "3 -> 4 -> 5 -> End");
+
+        let mut list = LinkedList::new();
+        list.extend_fast(0..5);
+        assert_eq!(lengths(&list.split_into(1)), vec![5]);
+
+        let mut list = LinkedList::new();
+        list.extend_fast(0..2);
+        assert_consistent(&list);
+        assert_eq!(lengths(&list.split_into(4)), vec![1, 1, 0, 0]);
+    }
+
+    // Test that concatenating the parts reproduces the original order.
+    #[test]
+    fn test_split_into_reconstruction() {
+        let mut list = LinkedList::new();
+        list.extend_fast(0..23);
+
+        assert_consistent(&list);
+        let parts = list.split_into(5);
+        let joined: Vec<_> = parts.iter().flat_map(|part| part.iter().copied()).collect();
+        assert_eq!(joined, (0..23).collect::<Vec<_>>());
+        assert_eq!(parts.iter().map(LinkedList::len).sum::<usize>(), 23);
+    }
+
+    // Test that splitting into zero parts is rejected.
+    #[test]
+    #[should_panic(expected = "n must be non-zero")]
+    fn test_split_into_zero() {
+        LinkedList::<i32>::new().split_into(0);
+    }
+
+    // Test overlap checks between two lists.
+    #[test]
+    fn test_is_disjoint() {
+        let mut tags = LinkedList::new();
+        tags.extend_fast(["a", "b", "c"].into_iter());
+
+        let mut first = LinkedList::new();
+        first.extend_fast(["a", "x"].into_iter());
+        let mut last = LinkedList::new();
+        last.extend_fast(["y", "z", "c"].into_iter());
+        let mut none = LinkedList::new();
+        none.extend_fast(["x", "y"].into_iter());
+        let empty = LinkedList::new();
+
+        for (other, disjoint) in [(&first, false), (&last, false), (&none, true), (&empty, true)] {
+            assert_eq!(tags.is_disjoint(other), disjoint);
+            assert_eq!(tags.is_disjoint_hashed(other), disjoint);
+            assert_eq!(other.is_disjoint(&tags), disjoint);
+            assert_eq!(other.is_disjoint_hashed(&tags), disjoint);
+        }
+    }
+
+    // Test that the naive and hashed overlap checks agree on random lists.
+    #[test]
+    fn test_is_disjoint_implementations_agree() {
+        let mut rng = crate::testing::XorShift::new(0xd15);
+        for _ in 0..200 {
+            let mut left = LinkedList::new();
+            let mut right = LinkedList::new();
+            for _ in 0..rng.below(6) {
+                left.push_front(rng.below(20));
+            }
+            for _ in 0..rng.below(6) {
+                right.push_front(rng.below(20));
+            }
+
+            assert_eq!(left.is_disjoint(&right), left.is_disjoint_hashed(&right));
+        }
+    }
+
+    // Test exchanging ranges between two lists by relinking their nodes.
+    #[test]
+    fn test_swap_range() {
+        for range in [0..0, 1..3, 0..2, 2..4, 0..4] {
+            let mut left = LinkedList::new();
+            left.extend_fast(0..4);
+            let mut right = LinkedList::new();
+            right.extend_fast(10..16);
+            let left_addrs: Vec<*const i32> = left.iter().map(|elem| elem as *const i32).collect();
+            let right_addrs: Vec<*const i32> = right.iter().map(|elem| elem as *const i32).collect();
+
+            left.swap_range(&mut right, range.clone());
+            assert_eq!((left.len(), right.len()), (4, 6));
+
+            let mut expected_left: Vec<i32> = (0..4).collect();
+            let mut expected_right: Vec<i32> = (10..16).collect();
+            expected_left[range.clone()].swap_with_slice(&mut expected_right[range.clone()]);
+            assert_eq!(left.iter().copied().collect::<Vec<_>>(), expected_left);
+            assert_eq!(right.iter().copied().collect::<Vec<_>>(), expected_right);
+
+            let mut expected_left_addrs = left_addrs.clone();
+            let mut expected_right_addrs = right_addrs.clone();
+            expected_left_addrs[range.clone()].swap_with_slice(&mut expected_right_addrs[range.clone()]);
+            assert_eq!(left.iter().map(|elem| elem as *const i32).collect::<Vec<_>>(), expected_left_addrs);
+            assert_eq!(right.iter().map(|elem| elem as *const i32).collect::<Vec<_>>(), expected_right_addrs);
+        }
+    }
+
+    // Test that swap_range rejects ranges past the end of the shorter list.
+    #[test]
+    #[should_panic(expected = "swap_range: range 2..5 out of bounds for lists of length 4 and 6")]
+    fn test_swap_range_out_of_bounds() {
+        let mut left = LinkedList::new();
+        left.extend_fast(0..4);
+        let mut right = LinkedList::new();
+        right.extend_fast(0..6);
+        left.swap_range(&mut right, 2..5);
+    }
+
+    // Test draining expired timers on a sequence of ticks.
+    #[test]
+    fn test_drain_expired() {
+        let mut timers = LinkedList::new();
+        timers.extend_fast([1, 3, 3, 5, 8, 13].into_iter());
+
+        let ticks: [(i32, &[i32]); 5] = [(0, &[]), (3, &[1, 3, 3]), (4, &[]), (13, &[5, 8, 13]), (20, &[])];
+        for (now, expected) in ticks {
+            let drained = timers.drain_expired(now, |deadline| *deadline);
+            assert_eq!(drained.iter().copied().collect::<Vec<_>>(), expected);
+            assert_eq!(drained.len(), expected.len());
+        }
+        assert!(timers.is_empty());
+
+        let mut jobs = LinkedList::new();
+        jobs.extend_fast([(2, "a"), (4, "b"), (9, "c")].into_iter());
+        let drained = jobs.drain_expired(100, |job| job.0);
+        assert_eq!(drained.len(), 3);
+        assert!(jobs.is_empty());
+    }
+
+    // Test refilling a reused vector and partially filling a slice.
+    #[test]
+    fn test_copy_into() {
+        let mut buf = Vec::new();
+        let mut list = LinkedList::new();
+        assert_eq!(list.copy_into(&mut buf), &[] as &[i32]);
+
+        list.extend_fast(0..8);
+        assert_eq!(list.copy_into(&mut buf), &[0, 1, 2, 3, 4, 5, 6, 7]);
+        let capacity = buf.capacity();
+        let ptr = buf.as_ptr();
+
+        let mut shorter = LinkedList::new();
+        shorter.extend_fast(10..13);
+        assert_eq!(shorter.copy_into(&mut buf), &[10, 11, 12]);
+        assert_eq!(list.copy_into(&mut buf).len(), 8);
+        assert_eq!((buf.capacity(), buf.as_ptr()), (capacity, ptr));
+
+        let mut slots = [-1; 5];
+        assert_eq!(shorter.copy_into_slice(&mut slots), 3);
+        assert_eq!(slots, [10, 11, 12, -1, -1]);
+        assert_eq!(list.copy_into_slice(&mut slots), 5);
+        assert_eq!(slots, [0, 1, 2, 3, 4]);
+        assert_eq!(list.copy_into_slice(&mut []), 0);
+
+        assert_consistent(&list);
+    }
+
+    // Test every positional entry method at the head, middle, tail, and end of a list.
+    #[test]
+    fn test_entry_at() {
+        for index in 0..3 {
+            let mut list = LinkedList::new();
+            list.extend_fast(0..3);
+            match list.entry_at(index) {
+                Entry::Occupied(mut entry) => {
+                    assert_eq!(entry.index(), index);
+                    assert_eq!(*entry.get(), index as i32);
+                    *entry.get_mut() *= 10;
+                    entry.insert_after(-1);
+                    let entry = entry.insert_before(-2);
+                    assert_eq!(entry.index(), index + 1);
+                    assert_eq!(entry.remove(), index as i32 * 10);
+                }
+                Entry::Vacant(_) => panic!("index {} is in range", index),
+            }
+
+            let mut expected = vec![0, 1, 2];
+            expected.splice(index..=index, [-2, -1]);
+            assert_eq!(list.len(), 4);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        }
+
+        let mut list = LinkedList::new();
+        list.extend_fast(0..3);
+        match list.entry_at(3) {
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.index(), 3);
+                *entry.insert(3) += 1;
+            }
+            Entry::Occupied(_) => panic!("index 3 is past the end"),
+        }
+        assert_eq!(format!("{:?}", list), "0 -> 1 -> 2 -> 4 -> End");
+
+        let mut empty = LinkedList::new();
+        match empty.entry_at(0) {
+            Entry::Vacant(entry) => entry.insert(1),
+            Entry::Occupied(_) => panic!("empty list has no elements"),
+        };
+        assert_eq!(empty.len(), 1);
+
+        assert_consistent(&list);
+    }
+
+    // Test that entry_at rejects positions past the end of the list.
+    #[test]
+    #[should_panic(expected = "entry_at: index 4 out of range for list of length 3")]
+    fn test_entry_at_out_of_range() {
+        let mut list = LinkedList::new();
+        list.extend_fast(0..3);
+        list.entry_at(4);
+    }
+
+    // Test duplicate detection with both implementations.
+    #[test]
+    fn test_first_duplicate() {
+        let cases = [
+            (&[][..], None),
+            (&[1, 2, 3][..], None),
+            (&[1, 1, 2][..], Some((1, 1))),
+            (&[1, 2, 3, 4, 5, 1][..], Some((5, 1))),
+            (&[7, 7, 7, 7][..], Some((1, 7))),
+            (&[1, 2, 3, 2, 1][..], Some((3, 2))),
+        ];
+
+        for (elems, expected) in cases {
+            let mut list = LinkedList::new();
+            list.extend_fast(elems.iter().copied());
+            let expected = expected.as_ref().map(|(index, elem)| (*index, elem));
+            assert_eq!(list.first_duplicate(), expected);
+            assert_eq!(list.first_duplicate_hashed(), expected);
+            assert_eq!(list.has_duplicates(), expected.is_some());
+            assert_eq!(list.has_duplicates_hashed(), expected.is_some());
+        }
+    }
+
+    // Test that the naive and hashed duplicate checks agree on random lists.
+    #[test]
+    fn test_first_duplicate_implementations_agree() {
+        let mut rng = crate::testing::XorShift::new(0xd0b);
+        for _ in 0..200 {
+            let mut list = LinkedList::new();
+            for _ in 0..rng.below(12) {
+                list.push_front(rng.below(16));
+            }
+
+            assert_eq!(list.first_duplicate(), list.first_duplicate_hashed());
+        }
+    }
+
+    // Test every entry method at the front of an empty and a non-empty list.
+    #[test]
+    fn test_front_entry() {
+        let mut list = LinkedList::new();
+        match list.front_entry() {
+            Entry::Vacant(entry) => *entry.insert(1) += 10,
+            Entry::Occupied(_) => panic!("empty list has an occupied front"),
+        }
+        assert_eq!(format!("{:?}", list), "11 -> End");
+        assert_eq!(list.len(), 1);
+
+        match list.front_entry() {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.get(), &11);
+                *entry.get_mut() = 2;
+                let entry = entry.insert_before(1);
+                assert_eq!(entry.get(), &2);
+                *entry.into_mut() += 1;
+            }
+            Entry::Vacant(_) => panic!("non-empty list has a vacant front"),
+        }
+        assert_eq!(format!("{:?}", list), "1 -> 3 -> End");
+        assert_eq!(list.len(), 2);
+
+        match list.front_entry() {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), 1),
+            Entry::Vacant(_) => panic!("non-empty list has a vacant front"),
+        }
+        assert_eq!(format!("{:?}", list), "3 -> End");
+        assert_eq!(list.len(), 1);
+
+        assert_consistent(&list);
+    }
+
+    // Test that long lists are truncated in Debug output unless the full listing is requested.
+    #[test]
+    fn test_debug_truncation() {
+        let mut list = LinkedList::new();
+        list.extend_fast(0..1000);
+
+        let expected: Vec<_> = (0..32).map(|i| format!("{} -> ", i)).collect();
+        assert_eq!(format!("{:?}", list), format!("{}.. 968 more -> End", expected.concat()));
+        assert_eq!(format!("{:.2?}", list), "0 -> 1 -> .. 998 more -> End");
+
+        let full: Vec<_> = (0..1000).map(|i| format!("{} -> ", i)).collect();
+        assert_eq!(format!("{:#?}", list), format!("{}End", full.concat()));
+
+        let mut short = LinkedList::new();
+        short.extend_fast(0..32);
+        assert!(!format!("{:?}", short).contains("more"));
+
+        assert_consistent(&list);
+    }
+
+    // Test finding the positions of the largest and smallest keys.
+    #[test]
+    fn test_position_by_key() {
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.position_max_by_key(|x| *x), None);
+
         let mut list = LinkedList::new();
-        list.append(1);
-        list.append(2);
-        list.append(3);
+        list.extend_fast([9i32, 2, 5, 9, 1, 1].into_iter());
+        assert_eq!(list.position_max_by_key(|x| *x), Some(0));
+        assert_eq!(list.position_min_by_key(|x| *x), Some(4));
+        assert_eq!(list.position_max_by_key(|x| -x), Some(4));
+        assert_eq!(list.position_min_by_key(|x| (*x - 5).abs()), Some(2));
+
+        assert_consistent(&list);
+    }
+
+    // Test moving an element to the front by relinking.
+    #[test]
+    fn test_swap_to_front() {
+        let mut list = LinkedList::new();
+        list.extend_fast(0..5);
+
+        list.swap_to_front(0);
+        assert_eq!(format!("{:?}", list), "0 -> 1 -> 2 -> 3 -> 4 -> End");
+
+        let moved = list.iter().nth(4).unwrap() as *const i32;
+        let kept = list.iter().nth(2).unwrap() as *const i32;
+        list.swap_to_front(4);
+        assert_eq!(format!("{:?}", list), "4 -> 0 -> 1 -> 2 -> 3 -> End");
+        assert_eq!(list.iter().next().unwrap() as *const i32, moved);
+        assert_eq!(list.iter().nth(3).unwrap() as *const i32, kept);
+
+        let best = list.position_max_by_key(|x| *x % 4).unwrap();
+        list.swap_to_front(best);
+        assert_eq!(format!("{:?}", list), "3 -> 4 -> 0 -> 1 -> 2 -> End");
+        assert_eq!(list.len(), 5);
+
+        assert_consistent(&list);
+    }
+
+    // Test that moving an out-of-range index panics.
+    #[test]
+    #[should_panic(expected = "swap_to_front: index 3 out of range for list of length 3")]
+    fn test_swap_to_front_out_of_range() {
+        let mut list = LinkedList::new();
+        list.extend_fast(0..3);
+        list.swap_to_front(3);
+    }
+
+    // Test concatenating lists with the `+` and `+=` operators.
+    #[test]
+    fn test_add_operators() {
+        let list_of = |range: std::ops::Range<i32>| {
+            let mut list = LinkedList::new();
+            list.extend_fast(range);
+            list
+        };
+
+        let joined = list_of(0..2) + list_of(2..5);
+        assert_eq!(joined.len(), 5);
+        assert_eq!(format!("{:?}", joined), "0 -> 1 -> 2 -> 3 -> 4 -> End");
+
+        let chained = list_of(0..1) + list_of(1..2) + list_of(2..3);
+        assert_eq!(format!("{:?}", chained), "0 -> 1 -> 2 -> End");
+
+        let with_empties = LinkedList::new() + list_of(0..2) + LinkedList::new();
+        assert_eq!(with_empties.len(), 2);
+        assert_eq!(format!("{:?}", with_empties), "0 -> 1 -> End");
+
+        let mut list = list_of(0..1);
+        list += list_of(1..3);
+        list += LinkedList::new();
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{:?}", list), "0 -> 1 -> 2 -> End");
+
+        assert_consistent(&list);
+    }
+
+    // Test concatenating many lists with the free function.
+    #[test]
+    fn test_concat() {
+        let lists = (0..4).map(|i| {
+            let mut list = LinkedList::new();
+            list.extend_fast(0..i);
+            list
+        });
+
+        let joined = concat(lists);
+        assert_eq!(joined.len(), 6);
+        assert_eq!(format!("{:?}", joined), "0 -> 0 -> 1 -> 0 -> 1 -> 2 -> End");
+        assert!(concat(Vec::<LinkedList<i32>>::new()).is_empty());
+    }
+
+    // Test clamped indexing on empty lists and for in-range and out-of-range indices.
+    #[test]
+    fn test_get_clamped() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.get_clamped(0), None);
+
+        list.extend_fast(0..3);
+        assert_eq!(list.get_clamped(0), Some(&0));
+        assert_eq!(list.get_clamped(2), Some(&2));
+        assert_eq!(list.get_clamped(3), Some(&2));
+        assert_eq!(list.get_clamped(usize::MAX), Some(&2));
+
+        assert_consistent(&list);
+    }
+
+    /// Payload whose `Clone` implementation panics, proving that relinking never clones.
+    #[derive(Debug, PartialEq)]
+    struct NoClone(i32);
+
+    impl Clone for NoClone {
+        fn clone(&self) -> Self {
+            panic!("NoClone was cloned");
+        }
+    }
+
+    // Test that extend_list relinks the other list's nodes without cloning any element.
+    #[test]
+    fn test_extend_list() {
+        let mut list = LinkedList::new();
+        list.extend_list(LinkedList::new());
+        assert!(list.is_empty());
+
+        let mut other = LinkedList::new();
+        other.extend_fast((1..3).map(NoClone));
+        list.extend_list(other);
+        assert_eq!(list.len(), 2);
+
+        let mut other = LinkedList::new();
+        other.extend_fast((3..6).map(NoClone));
+        list.extend_list(other);
+        list.extend_list(LinkedList::new());
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().map(|elem| elem.0).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+        assert_consistent(&list);
+    }
+
+    // Test that the look-ahead fold agrees with a plain loop on short and long lists.
+    #[test]
+    fn test_iter_fold() {
+        for len in [0, 1, 2, 3, 100_000] {
+            let mut list = LinkedList::new();
+            list.extend_fast(0..len as u32);
+
+            let mut expected = Vec::new();
+            for elem in list.iter() {
+                expected.push(*elem);
+            }
+            let folded = list.iter().fold(Vec::new(), |mut acc, elem| {
+                acc.push(*elem);
+                acc
+            });
+            assert_eq!(folded, expected);
+            assert_eq!(list.iter().fold(0, |acc, elem| acc + u64::from(*elem)), (0..len as u64).sum());
+        }
+
+        let mut list = LinkedList::new();
+        list.extend_fast(0..4);
+        let mut iter = list.iter();
+        iter.next();
+        assert_eq!(iter.fold(0, |acc, elem| acc * 10 + elem), 123);
+
+        assert_consistent(&list);
+    }
+
+    // Test the operation counters recorded by the stats feature.
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_stats() {
+        use crate::stats::ListStats;
+
+        let mut list = LinkedList::new();
+        list.extend_fast(0..5);
+        assert_eq!(list.stats(), ListStats { allocations: 5, ..ListStats::default() });
+
+        // Without a tail pointer, appending to an n-element list follows n links.
+        list.reset_stats();
+        list.push_back(5);
+        assert_eq!(list.stats(), ListStats { allocations: 1, traversal_steps: 5, ..ListStats::default() });
+
+        list.reset_stats();
+        list.push_front(-1);
+        list.pop_front();
+        list.swap_to_front(3);
+        assert_eq!(list.stats(), ListStats { allocations: 1, frees: 1, traversal_steps: 3, relinks: 1 });
+
+        list.reset_stats();
+        let mut other = LinkedList::new();
+        other.push_front(9);
+        list.extend_list(other);
+        list.extend_list(LinkedList::new());
+        assert_eq!(list.stats().relinks, 1);
+        assert_eq!(list.stats().traversal_steps, 6);
+
+        list.reset_stats();
+        list.clear();
+        assert_eq!(list.stats(), ListStats { frees: 7, ..ListStats::default() });
+
+        assert_consistent(&list);
+    }
+
+    /// Asserts that the list's recorded length matches its nodes.
+    fn assert_consistent<T>(list: &LinkedList<T>) {
+        assert_eq!(list.assert_consistent(), Ok(()));
+    }
+
+    /// Builds a list of drop-counting elements holding `0..len`.
+    fn counter_list(len: i32, drops: &std::rc::Rc<std::cell::Cell<usize>>) -> LinkedList<DropCounter> {
+        let mut list = LinkedList::new();
+        list.extend_fast((0..len).map(|value| DropCounter { value, drops: drops.clone() }));
+        list
+    }
+
+    // Test that a panicking iterator leaves extend_fast's list counted and droppable.
+    #[test]
+    fn test_extend_fast_unwind() {
+        for panic_at in [0, 2, 4] {
+            let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+            let mut list = counter_list(2, &drops);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.extend_fast((0..5).map(|value| {
+                    assert!(value != panic_at, "boom");
+                    DropCounter { value, drops: drops.clone() }
+                }));
+            }));
+
+            assert!(result.is_err());
+            assert_eq!(list.len(), 2 + panic_at as usize);
+            assert_eq!(list.iter().count(), list.len());
+            drop(list);
+            assert_eq!(drops.get(), 2 + panic_at as usize);
+        }
+    }
+
+    // Test that a panicking conversion drops every element of try_map exactly once.
+    #[test]
+    fn test_try_map_unwind() {
+        for panic_at in [0, 2, 4] {
+            let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+            let list = counter_list(5, &drops);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.try_map(|elem| {
+                    assert!(elem.value != panic_at, "boom");
+                    Ok::<_, ()>(elem)
+                })
+            }));
+
+            assert!(result.is_err());
+            assert_eq!(drops.get(), 5);
+        }
+    }
+
+    // Test that a panicking key leaves drain_expired's list unchanged.
+    #[test]
+    fn test_drain_expired_unwind() {
+        for panic_at in [0, 2, 4] {
+            let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+            let mut list = counter_list(5, &drops);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.drain_expired(10, |elem| {
+                    assert!(elem.value != panic_at, "boom");
+                    elem.value
+                })
+            }));
+
+            assert!(result.is_err());
+            assert_eq!(list.len(), 5);
+            assert_eq!(list.iter().map(|elem| elem.value).collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+            assert_eq!(drops.get(), 0);
+            drop(list);
+            assert_eq!(drops.get(), 5);
+        }
+    }
+
+    // Test borrowing the rest of a partially consumed iterator as a view.
+    #[test]
+    fn test_iter_remainder() {
+        let mut list = LinkedList::new();
+        list.extend_fast(1..8);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.by_ref().take(3).copied().collect::<Vec<_>>(), [1, 2, 3]);
+        let view = iter.remainder();
+        assert_eq!(iter.next(), Some(&4));
+
+        assert_eq!(view, [4, 5, 6, 7]);
+        assert_eq!(view.len(), 4);
+        assert_eq!(view.front(), Some(&4));
+        assert!(std::ptr::eq(view.front().unwrap(), list.get_clamped(3).unwrap()));
+        assert_eq!(view.into_iter().sum::<i32>(), 22);
+
+        assert_consistent(&list);
+    }
+
+    // Test viewing a suffix of the list by index.
+    #[test]
+    fn test_view_from() {
+        let mut list = LinkedList::new();
+        list.extend_fast(1..4);
+
+        assert_eq!(list.view_from(0), [1, 2, 3]);
+        assert_eq!(list.view_from(2), [3]);
+        assert!(list.view_from(3).is_empty());
+        assert_eq!(list.view_from(3), []);
+        assert_eq!(list.view_from(3).front(), None);
+        assert_eq!(format!("{:?}", list.view_from(1)), "[2, 3]");
+
+        assert_consistent(&list);
+    }
+
+    // Test that viewing past the end of the list panics.
+    #[test]
+    #[should_panic(expected = "view_from: index 4 out of range for list of length 3")]
+    fn test_view_from_out_of_range() {
+        let mut list = LinkedList::new();
+        list.extend_fast(1..4);
+        list.view_from(4);
+    }
+
+    // Test that assert_consistent reports a length that disagrees with the nodes.
+    #[test]
+    fn test_assert_consistent() {
+        let mut list = LinkedList::new();
+        list.extend_fast(1..4);
+        assert_eq!(list.assert_consistent(), Ok(()));
+
+        list.length = 2;
+        assert_eq!(list.assert_consistent(), Err(ConsistencyError::LengthMismatch { expected: 2, actual: 3 }));
+        assert_eq!(
+            list.assert_consistent().unwrap_err().to_string(),
+            "the list records 2 elements but links 3"
+        );
+        list.length = 3;
+    }
+
+    // Test that split_by and into_split_by segment a list like str::split.
+    #[test]
+    fn test_split_by() {
+        for text in ["", "abc", ",", ",,,", "a,b,c", "a,,b", ",a,b,", "ab,,,cd,"] {
+            let mut list = LinkedList::new();
+            list.extend_fast(text.chars().collect::<Vec<_>>().into_iter());
+            let expected: Vec<Vec<char>> = text.split(',').map(|part| part.chars().collect()).collect();
+
+            let cloned = list.split_by(|c| *c == ',');
+            for segment in cloned.iter() {
+                assert_consistent(segment);
+            }
+            let cloned: Vec<Vec<char>> = cloned.iter().map(|segment| segment.iter().copied().collect()).collect();
+            assert_eq!(cloned, expected, "split_by on {:?}", text);
+            assert_consistent(&list);
+
+            let segments = list.into_split_by(|c| *c == ',');
+            assert_consistent(&segments);
+            assert_eq!(segments.len(), expected.len());
+            let mut joined = Vec::new();
+            for (index, segment) in segments.iter().enumerate() {
+                assert_consistent(segment);
+                if index > 0 {
+                    joined.push(',');
+                }
+                joined.extend(segment.iter().copied());
+            }
+            assert_eq!(joined.into_iter().collect::<String>(), text);
+        }
+    }
+
+    // Test merging adjacent elements with coalesce.
+    #[test]
+    fn test_coalesce() {
+        type Span = (i32, i32);
+
+        fn merge_ranges(a: Span, b: Span) -> Result<Span, (Span, Span)> {
+            if b.0 <= a.1 {
+                Ok((a.0, a.1.max(b.1)))
+            } else {
+                Err((a, b))
+            }
+        }
+
+        for (ranges, expected) in [
+            (&[(0, 2), (1, 4), (4, 5), (7, 8), (8, 9), (11, 12)][..], &[(0, 5), (7, 9), (11, 12)][..]),
+            (&[(0, 1), (2, 3), (4, 5)][..], &[(0, 1), (2, 3), (4, 5)][..]),
+            (&[(0, 9), (1, 2), (3, 4), (5, 6)][..], &[(0, 9)][..]),
+            (&[(3, 4)][..], &[(3, 4)][..]),
+            (&[][..], &[][..]),
+        ] {
+            let mut list = LinkedList::new();
+            list.extend_fast(ranges.iter().copied());
+            list.coalesce(merge_ranges);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            assert_consistent(&list);
+        }
+
+        let mut list = LinkedList::new();
+        list.extend_fast([1, 1, 2, 3, 3, 3, 1].into_iter());
+        list.coalesce(|a, b| if a == b { Ok(a) } else { Err((a, b)) });
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 1]);
+        assert_eq!(list.len(), 4);
+    }
+
+    // Test that coalescing reuses the surviving nodes and frees only the absorbed ones
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_coalesce_allocates_nothing() {
+        use crate::stats::ListStats;
+
+        let mut list = LinkedList::new();
+        list.extend_fast([1, 1, 2, 3, 3, 3, 1].into_iter());
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut counted = counter_list(6, &drops);
+        list.reset_stats();
+        counted.reset_stats();
+
+        list.coalesce(|a, b| if a == b { Ok(a) } else { Err((a, b)) });
+        assert_eq!(list.stats(), ListStats { frees: 3, ..ListStats::default() });
+
+        counted.coalesce(|a, b| if a.value / 2 == b.value / 2 { Ok(a) } else { Err((a, b)) });
+        assert_eq!(counted.stats(), ListStats { frees: 3, ..ListStats::default() });
+        assert_eq!(counted.len(), 3);
+        assert_eq!(drops.get(), 3);
+        assert_consistent(&counted);
+        drop(counted);
+        assert_eq!(drops.get(), 6);
+    }
+
+    // Test reordering the list by a permutation of its indices.
+    #[test]
+    fn test_apply_permutation() {
+        let mut rng = crate::testing::XorShift::new(0x9e3d);
+        let mut perm: Vec<usize> = (0..20).collect();
+        for i in (1..perm.len()).rev() {
+            perm.swap(i, rng.below(i + 1));
+        }
+        let values: Vec<i32> = (0..20).map(|i| i * 3).collect();
+        let cases = [(0..20).collect::<Vec<_>>(), (0..20).rev().collect(), perm];
+
+        for perm in &cases {
+            let mut list = LinkedList::new();
+            list.extend_fast(values.iter().copied());
+            list.apply_permutation(perm);
+            let expected: Vec<i32> = perm.iter().map(|&src| values[src]).collect();
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            assert_consistent(&list);
+        }
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.apply_permutation(&[]);
+        assert_consistent(&empty);
+    }
+
+    // Test that an invalid permutation is rejected before the list is touched.
+    #[test]
+    fn test_apply_permutation_rejects_invalid() {
+        let mut list = LinkedList::new();
+        list.extend_fast(1..4);
+        for perm in [&[0, 1, 1][..], &[0, 1, 3][..], &[0, 1][..], &[2, 1, 0, 3][..]] {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.apply_permutation(perm)));
+            assert!(result.is_err());
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+            assert_consistent(&list);
+        }
+    }
+
+    // Test a SmallList against a `VecDeque` oracle, repeatedly crossing the inline boundary.
+    #[test]
+    fn test_small_list_model_against_vec_deque() {
+        let mut rng = crate::testing::XorShift::new(0x5a11);
+        for _ in 0..20 {
+            let mut list = SmallList::new();
+            let mut oracle = std::collections::VecDeque::new();
+
+            for _ in 0..200 {
+                let value = rng.below(16) as i32;
+                // Popping twice as often as pushing keeps the list around 0-2 elements.
+                match rng.below(7) {
+                    0 => {
+                        list.push_front(value);
+                        oracle.push_front(value);
+                    }
+                    1 => {
+                        list.push_back(value);
+                        oracle.push_back(value);
+                    }
+                    2 | 3 => assert_eq!(list.pop_front(), oracle.pop_front()),
+                    4 | 5 => assert_eq!(list.pop_back(), oracle.pop_back()),
+                    _ => {
+                        if rng.below(10) == 0 {
+                            list.clear();
+                            oracle.clear();
+                        }
+                    }
+                }
+
+                assert_eq!(list.len(), oracle.len());
+                assert_eq!(list.is_empty(), oracle.is_empty());
+                assert!(list.iter().eq(oracle.iter()));
+                assert_consistent(&list.rest);
+            }
+        }
+    }
+
+    // Test that a SmallList moves elements across the inline slot in order.
+    #[test]
+    fn test_small_list_boundary() {
+        let mut list = SmallList::default();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.first, Some(2));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+    }
+
+    // Test that a SmallList holding at most one element never allocates.
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_small_list_allocations() {
+        let mut list = SmallList::new();
+        for i in 0..10 {
+            list.push_back(i);
+            assert_eq!(list.pop_front(), Some(i));
+            list.push_front(i);
+            assert_eq!(list.pop_back(), Some(i));
+        }
+        assert_eq!(list.stats().allocations, 0);
+
+        list.push_back(0);
+        list.push_back(1);
+        assert_eq!(list.stats().allocations, 1);
+    }
+
+    // Test eq_by and cmp_by with custom comparisons and length mismatches.
+    #[test]
+    fn test_eq_by_and_cmp_by() {
+        let mut floats = LinkedList::new();
+        floats.extend_fast([1.0, 2.0, 3.0].into_iter());
+        let close = |a: &f64, b: &f64| (a - b).abs() < 1e-9;
+        assert!(floats.eq_by([1.0, 2.0 + 1e-12, 3.0], close));
+        assert!(!floats.eq_by([1.0, 2.1, 3.0], close));
+        assert!(!floats.eq_by([1.0, 2.0], close));
+        assert!(!floats.eq_by([1.0, 2.0, 3.0, 4.0], close));
+        assert!(floats.eq_by(&[1.0, 2.0, 3.0], |a, b| close(a, b)));
+
+        let mut words = LinkedList::new();
+        words.extend_fast(["Apple", "banana"].into_iter());
+        let mut other = LinkedList::new();
+        other.extend_fast(["apple", "BANANA"].into_iter());
+        assert!(words.eq_by(other.iter(), |a, b| a.eq_ignore_ascii_case(b)));
+
+        let ignore_case = |a: &&str, b: &&str| a.to_lowercase().cmp(&b.to_lowercase());
+        assert_eq!(words.cmp_by(["APPLE", "Banana"], ignore_case), Ordering::Equal);
+        assert_eq!(words.cmp_by(["apple", "cherry"], ignore_case), Ordering::Less);
+        assert_eq!(words.cmp_by(["apple"], ignore_case), Ordering::Greater);
+        assert_eq!(words.cmp_by(["apple", "banana", "cherry"], ignore_case), Ordering::Less);
+        assert_eq!(LinkedList::<&str>::new().cmp_by(Vec::<&str>::new(), ignore_case), Ordering::Equal);
+    }
+
+    // Test that fallible pushes link elements like the infallible ones.
+    #[cfg(feature = "fallible-alloc")]
+    #[test]
+    fn test_try_push() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.try_push_back(2), Ok(()));
+        assert_eq!(list.try_push_front(1), Ok(()));
+        assert_eq!(list.try_push_back(3), Ok(()));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_consistent(&list);
+    }
+
+    // Test that a failed allocation hands the element back and leaves the list unchanged.
+    #[cfg(feature = "fallible-alloc")]
+    #[test]
+    fn test_try_push_allocation_failure() {
+        let mut list = LinkedList::new();
+        list.extend_fast(["a", "b"].map(String::from).into_iter());
+        let elem = String::from("kept");
+
+        FAIL_NEXT_ALLOCATION.with(|fail| fail.set(true));
+        let (err, elem) = list.try_push_front(elem).unwrap_err();
+        assert_eq!(err, AllocError);
+        assert_eq!(err.to_string(), "memory allocation failed");
+        FAIL_NEXT_ALLOCATION.with(|fail| fail.set(true));
+        let (_, elem) = list.try_push_back(elem).unwrap_err();
+
+        assert_eq!(elem, "kept");
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().collect::<Vec<_>>(), ["a", "b"]);
+        assert_consistent(&list);
+    }
+
+    // Test that the owning iterator yields elements in order and tracks its length.
+    #[test]
+    fn test_into_iter() {
+        let mut list = LinkedList::new();
+        list.extend_fast(["a", "b", "c"].map(String::from).into_iter());
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next().as_deref(), Some("a"));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), ["b", "c"]);
+
+        let mut boxed: LinkedList<Box<dyn Fn(i32) -> i32>> = LinkedList::new();
+        boxed.push_back(Box::new(|x| x + 1));
+        boxed.push_back(Box::new(|x| x * 2));
+        assert_eq!(boxed.into_iter().map(|f| f(5)).collect::<Vec<_>>(), [6, 10]);
+    }
+
+    // Test that dropping a partially consumed owning iterator drops the rest exactly once.
+    #[test]
+    fn test_into_iter_partial_drop() {
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut iter = counter_list(5, &drops).into_iter();
+
+        let first = iter.next().unwrap();
+        assert_eq!(first.value, 0);
+        assert_eq!(iter.next().map(|item| item.value), Some(1));
+        assert_eq!(drops.get(), 1);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+
+        drop(iter);
+        assert_eq!(drops.get(), 4);
+        drop(first);
+        assert_eq!(drops.get(), 5);
+    }
+
+    // Test that the list can be used in for loops by reference, mutable reference and value.
+    #[test]
+    fn test_into_iter_borrowed() {
+        fn sum<'a, I: IntoIterator<Item = &'a i32>>(items: I) -> i32 {
+            items.into_iter().sum()
+        }
+
+        let mut list = LinkedList::new();
+        list.extend_fast([1, 2, 3].into_iter());
+
+        let mut seen = Vec::new();
+        for value in &list {
+            seen.push(*value);
+        }
+        assert_eq!(seen, [1, 2, 3]);
+        assert_eq!(sum(&list), 6);
+
+        for value in &mut list {
+            *value *= 10;
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [10, 20, 30]);
+        assert_consistent(&list);
+
+        let mut extended = vec![0];
+        extended.extend(&list);
+        assert_eq!(extended, [0, 10, 20, 30]);
+
+        let mut owned = Vec::new();
+        for value in list {
+            owned.push(value);
+        }
+        assert_eq!(owned, [10, 20, 30]);
+    }
+
+    // Test that collecting into a list preserves order and sets the length.
+    #[test]
+    fn test_from_iterator() {
+        let empty: LinkedList<i32> = std::iter::empty().collect();
+        assert!(empty.is_empty());
+        assert_consistent(&empty);
+
+        let list: LinkedList<String> = vec!["a".to_string(), "b".to_string()].into_iter().collect();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().map(String::as_str).collect::<Vec<_>>(), ["a", "b"]);
+        assert_consistent(&list);
+
+        let list: LinkedList<i32> = (0..3).chain(10..12).filter(|x| x % 2 == 0).collect();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 2, 10]);
+        assert_consistent(&list);
+
+        let large = (0..1000).collect::<LinkedList<_>>();
+        assert_eq!(large.len(), 1000);
+        assert!(large.iter().copied().eq(0..1000));
+    }
+
+    // Test that scan_in_place turns a list into its prefix sums and delta-decodes it back.
+    #[test]
+    fn test_scan_in_place() {
+        let values = [3, 1, 4, 1, 5, 9];
+        let mut list: LinkedList<i32> = values.into_iter().collect();
+        let total = list.scan_in_place(0, |sum, x| {
+            *sum += *x;
+            *x = *sum;
+        });
+        let expected: Vec<i32> = values.iter().scan(0, |sum, x| {
+            *sum += x;
+            Some(*sum)
+        }).collect();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(total, 23);
+
+        list.scan_in_place(0, |prev, x| {
+            let current = *x;
+            *x -= *prev;
+            *prev = current;
+        });
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), values);
+        assert_consistent(&list);
+
+        let mut empty = LinkedList::<i32>::new();
+        assert_eq!(empty.scan_in_place(7, |_, _| unreachable!()), 7);
+    }
+
+    // Test that extend appends at the tail and updates the length.
+    #[test]
+    fn test_extend() {
+        let mut list = LinkedList::new();
+        list.extend(Vec::<i32>::new());
+        assert!(list.is_empty());
+
+        list.extend(vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        list.extend(&[4, 5]);
+        list.extend((6..8).chain(std::iter::once(8)));
+        list.extend(std::iter::empty::<i32>());
+        assert_eq!(list.len(), 8);
+        assert!(list.iter().copied().eq(1..=8));
+        assert_consistent(&list);
+    }
+
+    // Test that cloning a long list is iterative and yields an independent copy.
+    #[test]
+    fn test_clone() {
+        let list: LinkedList<u32> = (0..1_000_000).collect();
+        let mut copy = list.clone();
+        assert_eq!(copy.len(), list.len());
+        assert!(copy.iter().eq(list.iter()));
+
+        assert_eq!(copy.pop_front(), Some(0));
+        copy.push_front(42);
+        copy.push_back(7);
+        assert_eq!(list.iter().next(), Some(&0));
+        assert_eq!(list.len(), 1_000_000);
+        assert_eq!(copy.len(), 1_000_001);
+        assert_consistent(&list);
+        assert_consistent(&copy);
+    }
+
+    // Test the from_fn, repeat and default constructors.
+    #[test]
+    fn test_constructors() {
+        let mut calls = Vec::new();
+        let squares = LinkedList::from_fn(4, |index| {
+            calls.push(index);
+            index * index
+        });
+        assert_eq!(calls, [0, 1, 2, 3]);
+        assert_eq!(squares.len(), 4);
+        assert_eq!(squares.iter().copied().collect::<Vec<_>>(), [0, 1, 4, 9]);
+        assert_eq!(format!("{:?}", squares), "0 -> 1 -> 4 -> 9 -> End");
+        assert_consistent(&squares);
+
+        let words = LinkedList::repeat("ab".to_string(), 3);
+        assert_eq!(words.len(), 3);
+        assert_eq!(format!("{:?}", words), "\"ab\" -> \"ab\" -> \"ab\" -> End");
+        assert_consistent(&words);
+
+        assert!(LinkedList::from_fn(0, |_: usize| -> i32 { unreachable!() }).is_empty());
+        assert!(LinkedList::repeat(1, 0).is_empty());
+        assert!(LinkedList::<i32>::default().is_empty());
+    }
+
+    // Test that sort_by_cached_key matches the stable Vec sort, including on duplicate keys.
+    #[test]
+    fn test_sort_by_cached_key() {
+        let mut rng = crate::testing::XorShift::new(0x5eed);
+        for len in [0, 1, 2, 17, 500] {
+            let records: Vec<(u32, usize)> = (0..len).map(|id| (rng.below(8) as u32, id)).collect();
+            let mut list = LinkedList::new();
+            list.extend_fast(records.iter().copied());
+
+            let mut calls = 0;
+            list.sort_by_cached_key(|&(key, _)| {
+                calls += 1;
+                key
+            });
+            let mut expected = records.clone();
+            expected.sort_by_key(|&(key, _)| key);
+            assert_eq!(calls, len);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            assert_consistent(&list);
+        }
+
+        let mut large: LinkedList<u32> = (0..200_000u32).map(|x| x.wrapping_mul(2_654_435_761)).collect();
+        large.sort_by_cached_key(|&x| x);
+        assert_eq!(large.len(), 200_000);
+        assert!(large.iter().zip(large.iter().skip(1)).all(|(a, b)| a <= b));
+    }
+
+    // Test element-wise equality between lists.
+    #[test]
+    fn test_eq() {
+        let words = |items: &[&str]| items.iter().map(|item| item.to_string()).collect::<LinkedList<_>>();
+        assert_eq!(words(&["a", "b"]), words(&["a", "b"]));
+        assert_ne!(words(&["a", "b"]), words(&["b", "a"]));
+        assert_ne!(words(&["a", "b"]), words(&["a", "b", "c"]));
+        assert_ne!(words(&["a"]), words(&[]));
+        assert_eq!(words(&[]), LinkedList::new());
+
+        let mut list = words(&["a", "b"]);
+        let copy = list.clone();
+        list.push_back("c".to_string());
+        assert_eq!(list.pop_back().as_deref(), Some("c"));
+        assert_eq!(list, copy);
+    }
+
+    // Test that merge_sorted interleaves two sorted lists stably.
+    #[test]
+    fn test_merge_sorted() {
+        let mut list: LinkedList<(i32, char)> = [(1, 'a'), (3, 'a'), (3, 'b'), (7, 'a')].into_iter().collect();
+        let other: LinkedList<(i32, char)> = [(0, 'x'), (3, 'x'), (8, 'x'), (9, 'x')].into_iter().collect();
+        list.merge_sorted(other);
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            [(0, 'x'), (1, 'a'), (3, 'a'), (3, 'b'), (3, 'x'), (7, 'a'), (8, 'x'), (9, 'x')]
+        );
+        assert_eq!(list.len(), 8);
+        assert_consistent(&list);
+
+        let mut empty = LinkedList::new();
+        empty.merge_sorted((1..3).collect());
+        empty.merge_sorted(LinkedList::new());
+        assert_eq!(empty.iter().copied().collect::<Vec<_>>(), [1, 2]);
+        assert_consistent(&empty);
+    }
+
+    // Test that lists are ordered lexicographically, like vectors.
+    #[test]
+    fn test_ord() {
+        let list = |elems: &[u32]| elems.iter().copied().collect::<LinkedList<_>>();
+        assert!(list(&[1, 2]) < list(&[1, 2, 3]));
+        assert!(list(&[2]) > list(&[1, 9, 9]));
+        assert!(list(&[]) < list(&[0]));
+        assert_eq!(list(&[1, 2]).cmp(&list(&[1, 2])), Ordering::Equal);
+        assert_eq!(LinkedList::from_fn(2, |_| f64::NAN).partial_cmp(&LinkedList::repeat(f64::NAN, 2)), None);
+
+        let versions: [&[u32]; 6] = [&[1, 10], &[1, 2, 3], &[], &[1, 2], &[0, 9], &[1, 2]];
+        let mut lists: Vec<LinkedList<u32>> = versions.iter().map(|elems| list(elems)).collect();
+        lists.sort();
+        let mut expected: Vec<Vec<u32>> = versions.iter().map(|elems| elems.to_vec()).collect();
+        expected.sort();
+        assert_eq!(lists.iter().map(|l| l.iter().copied().collect::<Vec<_>>()).collect::<Vec<_>>(), expected);
 
-        assert_eq!(list.pop_back(), Some(3));
-        assert_eq!(list.pop_back(), Some(2));
-        assert_eq!(list.pop_back(), Some(1));
-        assert_eq!(list.pop_back(), None);
+        for a in &lists {
+            for b in &lists {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+                assert_eq!(a.cmp(b) == Ordering::Equal, a == b);
+                for c in &lists {
+                    if a <= b && b <= c {
+                        assert!(a <= c);
+                    }
+                }
+            }
+        }
     }
 
-    // Test clearing the list.
+    // Test that lists can be used as hash keys, hashing equal lists equally.
     #[test]
-    fn test_clear() {
+    // The `stats` counters are interior-mutable but take no part in hashing.
+    #[cfg_attr(feature = "stats", allow(clippy::mutable_key_type))]
+    fn test_hash() {
+        let path = |parts: &[&str]| parts.iter().map(|part| part.to_string()).collect::<LinkedList<_>>();
+        let hash_of = |list: &LinkedList<String>| {
+            use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher};
+            BuildHasherDefault::<DefaultHasher>::default().hash_one(list)
+        };
+        assert_eq!(hash_of(&path(&["a", "b"])), hash_of(&path(&["a", "b"])));
+        assert_ne!(hash_of(&path(&["ab"])), hash_of(&path(&["a", "b"])));
+
+        let mut seen = HashSet::new();
+        assert!(seen.insert(path(&["usr", "lib"])));
+        assert!(seen.insert(path(&["lib", "usr"])));
+        assert!(seen.insert(path(&[])));
+        assert!(!seen.insert(path(&["usr", "lib"])));
+        assert_eq!(seen.len(), 3);
+        assert!(seen.contains(&path(&["lib", "usr"])));
+    }
+
+    // Test that leaked elements outlive the list they came from.
+    #[test]
+    fn test_leak() {
+        fn needs_static(names: &[&'static mut String]) -> usize {
+            names.iter().map(|name| name.len()).sum()
+        }
+
+        let list: LinkedList<String> = ["alpha", "beta"].map(String::from).into_iter().collect();
+        let mut names: Vec<&'static mut String> = list.leak_elements().collect();
+        names[1].push('!');
+        assert_eq!(needs_static(&names), 10);
+        assert_eq!(*names[1], "beta!");
+
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut partial = counter_list(3, &drops).leak_elements();
+        assert_eq!(partial.next().map(|item| item.value), Some(0));
+        drop(partial);
+        assert_eq!(drops.get(), 2);
+
+        let leaked: &'static mut LinkedList<i32> = (1..4).collect::<LinkedList<_>>().leak();
+        leaked.push_back(4);
+        assert_eq!(leaked.len(), 4);
+        assert_consistent(leaked);
+    }
+
+    // Test the Display output and join with custom separators.
+    #[test]
+    fn test_display_and_join() {
+        assert_eq!(LinkedList::<i32>::new().to_string(), "");
+        assert_eq!(LinkedList::<i32>::new().join("/"), "");
+        assert_eq!(LinkedList::repeat(7, 1).to_string(), "7");
+        assert_eq!(LinkedList::repeat(7, 1).join("/"), "7");
+
+        let numbers: LinkedList<f64> = [1.5, 2.0, -3.25].into_iter().collect();
+        assert_eq!(numbers.to_string(), "1.5, 2, -3.25");
+        assert_eq!(format!("[{}]", numbers), "[1.5, 2, -3.25]");
+
+        let path: LinkedList<&str> = ["a", "b", "c"].into_iter().collect();
+        assert_eq!(path.join("/"), "a/b/c");
+        assert_eq!(path.join(""), "abc");
+    }
+
+    // Test the front and back accessors, reading and writing through them.
+    #[test]
+    fn test_front_back() {
+        let mut list = LinkedList::<String>::new();
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+        assert_eq!(list.front_mut(), None);
+        assert_eq!(list.back_mut(), None);
+
+        list.extend(["a", "b", "c"].map(String::from));
+        assert_eq!(list.front().map(String::as_str), Some("a"));
+        assert_eq!(list.back().map(String::as_str), Some("c"));
+
+        list.front_mut().unwrap().push('1');
+        list.back_mut().unwrap().push('3');
+        assert_eq!(list.iter().map(String::as_str).collect::<Vec<_>>(), ["a1", "b", "c3"]);
+        assert_eq!(list.pop_back().as_deref(), Some("c3"));
+        assert_eq!(list.pop_front().as_deref(), Some("a1"));
+        assert_eq!(list.front(), list.back());
+        assert_consistent(&list);
+    }
+
+    // Test decomposing a list into raw parts and rebuilding it.
+    #[test]
+    fn test_raw_parts() {
+        let list: LinkedList<String> = ["a", "b", "c"].map(String::from).into_iter().collect();
+        let (head, len) = list.into_raw_parts();
+        assert_eq!(len, 3);
+        assert_eq!(head.as_ref().map(|node| node.data().as_str()), Some("a"));
+        let list = LinkedList::from_raw_parts(head, len).unwrap();
+        assert_eq!(list.join(""), "abc");
+        assert_consistent(&list);
+
+        let (head, _) = list.into_raw_parts();
+        assert_eq!(
+            LinkedList::from_raw_parts(head, 2).err(),
+            Some(ConsistencyError::LengthMismatch { expected: 2, actual: 3 })
+        );
+
+        // Detach the chain after the first node and rebuild both halves.
+        let (mut head, _) = (1..=4).collect::<LinkedList<i32>>().into_raw_parts();
+        let rest = head.as_mut().and_then(|node| node.next.take());
+        let front = LinkedList::from_raw_parts(head, 1).unwrap();
+        let back = LinkedList::from_raw_parts(rest, 3).unwrap();
+        assert_eq!(front.iter().copied().collect::<Vec<_>>(), [1]);
+        assert_eq!(back.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_consistent(&back);
+        assert!(LinkedList::<i32>::from_raw_parts(None, 0).unwrap().is_empty());
+    }
+
+    // Test indexed access and mutation through get_mut.
+    #[test]
+    fn test_get() {
+        let mut list: LinkedList<i32> = (10..14).collect();
+        assert_eq!(list.get(0), Some(&10));
+        assert_eq!(list.get(3), Some(&13));
+        assert_eq!(list.get(4), None);
+        assert_eq!(list.get(usize::MAX), None);
+        assert_eq!(list.get_mut(4), None);
+
+        *list.get_mut(1).unwrap() = 0;
+        *list.get_mut(3).unwrap() *= 2;
+        assert_eq!(format!("{:?}", list), "10 -> 0 -> 12 -> 26 -> End");
+        assert_eq!(LinkedList::<i32>::new().get(0), None);
+        assert_consistent(&list);
+    }
+
+    // Test inserting at the front, in the middle and at the end
+    #[test]
+    fn test_insert() {
         let mut list = LinkedList::new();
-        list.append(1);
-        list.append(2);
-        list.clear();
+        list.insert(0, 2);
+        list.insert(0, 0);
+        list.insert(1, 1);
+        list.insert(3, 4);
+        list.insert(3, 3);
+        list.insert(list.len(), 5);
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+        assert_eq!(list.len(), 6);
+        assert_consistent(&list);
+    }
 
+    // Test that inserting past the end panics with the offending index
+    #[test]
+    #[should_panic(expected = "insert: index 3 out of range for list of length 2")]
+    fn test_insert_out_of_range() {
+        let mut list: LinkedList<i32> = [1, 2].into_iter().collect();
+        list.insert(3, 0);
+    }
+
+    // Test removing by index, interleaved with iteration
+    #[test]
+    fn test_remove_at_index() {
+        let mut list: LinkedList<i32> = (0..6).collect();
+        assert_eq!(list.remove(6), None);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(list.remove(2), Some(2));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 3, 4, 5]);
+        assert_eq!(list.remove(0), Some(0));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 3, 4, 5]);
+        assert_eq!(list.remove(list.len() - 1), Some(5));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 3, 4]);
+        assert_eq!(list.remove(usize::MAX), None);
+        assert_consistent(&list);
+
+        while list.remove(0).is_some() {}
         assert!(list.is_empty());
+        assert_eq!(list.remove(0), None);
+        assert_consistent(&list);
     }
 
-    // Test iterating over the list.
+    // Test global deduplication with both implementations.
     #[test]
-    fn test_iter() {
+    fn test_dedup_all() {
+        let cases: [(&[i32], &[i32]); 5] = [
+            (&[], &[]),
+            (&[1, 2, 3], &[1, 2, 3]),
+            (&[4, 4, 4, 4], &[4]),
+            (&[1, 2, 1, 3, 2, 4, 1], &[1, 2, 3, 4]),
+            (&[5, 6, 7, 6, 5], &[5, 6, 7]),
+        ];
+
+        for (elems, expected) in cases {
+            let mut list: LinkedList<i32> = elems.iter().copied().collect();
+            list.dedup_all();
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            assert_consistent(&list);
+
+            let mut list: LinkedList<i32> = elems.iter().copied().collect();
+            list.dedup_all_by_eq();
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+            assert_consistent(&list);
+        }
+    }
+
+    // Test that the hashed and naive deduplication agree on random lists.
+    #[test]
+    fn test_dedup_all_implementations_agree() {
+        let mut rng = crate::testing::XorShift::new(0xded0);
+        for _ in 0..200 {
+            let elems: Vec<usize> = (0..rng.below(12)).map(|_| rng.below(8)).collect();
+            let mut hashed: LinkedList<usize> = elems.iter().copied().collect();
+            let mut naive = hashed.clone();
+            hashed.dedup_all();
+            naive.dedup_all_by_eq();
+
+            assert_eq!(hashed, naive);
+            assert_eq!(hashed.len(), naive.iter().count());
+        }
+    }
+
+    // Test contains with a borrowed query, and find and find_mut stopping at the first match
+    #[test]
+    fn test_contains_and_find() {
+        let mut list: LinkedList<String> = ["ab", "cd", "ce"].iter().map(|s| s.to_string()).collect();
+        assert!(list.contains("cd"));
+        assert!(!list.contains("xy"));
+
+        let mut visited = 0;
+        let found = list.find(|s| {
+            visited += 1;
+            s.starts_with('c')
+        });
+        assert_eq!(found.map(String::as_str), Some("cd"));
+        assert_eq!(visited, 2);
+        assert_eq!(list.find(|s| s.is_empty()), None);
+
+        if let Some(s) = list.find_mut(|s| s.ends_with('e')) {
+            s.push('!');
+        }
+        assert_eq!(list.iter().map(String::as_str).collect::<Vec<_>>(), ["ab", "cd", "ce!"]);
+
+        let mut empty: LinkedList<String> = LinkedList::new();
+        assert!(!empty.contains("ab"));
+        assert_eq!(empty.find(|_| true), None);
+        assert_eq!(empty.find_mut(|_| true), None);
+    }
+
+    // Test in-place reversal, including a list long enough to overflow a recursive implementation
+    #[test]
+    fn test_reverse() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.reverse();
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        list.reverse();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1]);
+
+        let mut list: LinkedList<i32> = (0..5).collect();
+        list.reverse();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [4, 3, 2, 1, 0]);
+        assert_eq!(list.len(), 5);
+        list.reverse();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+        assert_consistent(&list);
+
+        let mut long: LinkedList<u32> = (0..1_000_000).collect();
+        long.reverse();
+        assert_eq!(long.len(), 1_000_000);
+        assert_eq!(long.front(), Some(&999_999));
+        assert_eq!(long.back(), Some(&0));
+    }
+
+    // Test reading lines, including empty input and a trailing newline
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_lines() {
+        let list = LinkedList::from_lines(&b""[..]).unwrap();
+        assert!(list.is_empty());
+
+        let list = LinkedList::from_lines(&b"one\ntwo\r\n\nthree\n"[..]).unwrap();
+        assert_eq!(list.iter().map(String::as_str).collect::<Vec<_>>(), ["one", "two", "", "three"]);
+        assert_consistent(&list);
+
+        let err = LinkedList::from_lines(&b"ok\n\xff\n"[..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    // Test parsing delimited records, with a parse failure at a known record
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_delimited() {
+        let parse = |s: &str| s.trim().parse::<i32>();
+        let list = LinkedList::from_delimited(&b"1, 2,3,"[..], b',', parse).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert!(LinkedList::from_delimited(&b""[..], b',', parse).unwrap().is_empty());
+
+        match LinkedList::from_delimited(&b"1;2;x;4"[..], b';', parse) {
+            Err(err @ FromReadError::Parse { line: 3, .. }) => {
+                assert_eq!(err.to_string(), "failed to parse record 3: invalid digit found in string");
+            }
+            other => panic!("expected a parse error at record 3, got {:?}", other),
+        }
+        match LinkedList::from_delimited(&b"1;\xff"[..], b';', parse) {
+            Err(FromReadError::Io { line: 2, error }) => assert_eq!(error.kind(), std::io::ErrorKind::InvalidData),
+            other => panic!("expected a read error at record 2, got {:?}", other),
+        }
+    }
+
+    // Test that a large input is appended without walking the list
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_delimited_large_input() {
+        let input: String = (0..100_000).map(|i| format!("{}\n", i)).collect();
+        let list = LinkedList::from_delimited(input.as_bytes(), b'\n', str::parse::<u32>).unwrap();
+        assert_eq!(list.len(), 100_000);
+        assert!(list.iter().copied().eq(0..100_000));
+        #[cfg(feature = "stats")]
+        assert_eq!(list.stats().traversal_steps, 0);
+    }
+
+    // Test appending a borrowed list, with either side empty
+    #[test]
+    fn test_append_list() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        let mut other: LinkedList<i32> = (3..6).collect();
+        list.append_list(&mut other);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+        assert_eq!(list.len(), 6);
+        assert!(other.is_empty());
+        assert_consistent(&list);
+        assert_consistent(&other);
+
+        list.append_list(&mut other);
+        assert_eq!(list.len(), 6);
+
+        other.append_list(&mut list);
+        assert_eq!(other.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+        assert!(list.is_empty());
+        assert_consistent(&other);
+
+        other.push_back(6);
+        assert_eq!(other.len(), 7);
+        list.push_back(7);
+        other.append_list(&mut list);
+        assert_eq!(other.back(), Some(&7));
+    }
+
+    // Test that reserved nodes are used by insertions and do not change the length
+    #[test]
+    fn test_reserve_then_fill() {
+        let mut list = LinkedList::with_capacity(4);
+        assert_eq!((list.len(), list.capacity()), (0, 4));
+
+        list.push_back(1);
+        list.push_front(0);
+        list.insert(2, 3);
+        list.extend([4, 5]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 3, 4, 5]);
+        assert_eq!((list.len(), list.capacity()), (5, 5));
+        assert_consistent(&list);
+
+        list.reserve(2);
+        list.reserve(1);
+        assert_eq!(list.capacity(), 7);
+        list.shrink_to_fit();
+        assert_eq!(list.capacity(), 5);
+    }
+
+    // Test that the allocation counter sees reserved nodes once, when they are reserved
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_reserve_allocation_count() {
         let mut list = LinkedList::new();
-        list.append(1);
-        list.append(2);
-        list.append(3);
+        list.reserve(10);
+        assert_eq!(list.stats().allocations, 10);
+        for i in 0..10 {
+            list.push_back(i);
+        }
+        assert_eq!(list.stats().allocations, 10);
+        list.push_back(10);
+        assert_eq!(list.stats().allocations, 11);
+    }
+
+    // Test that dropping a list with unused reserved nodes only drops the real elements
+    #[test]
+    fn test_reserve_then_drop() {
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut list = LinkedList::with_capacity(8);
+        list.push_front(DropCounter { value: 1, drops: drops.clone() });
+        list.push_front(DropCounter { value: 2, drops: drops.clone() });
+        assert_eq!(list.capacity(), 8);
+
+        drop(list);
+        assert_eq!(drops.get(), 2);
+    }
+
+    // Test draining fully, stopping early and dropping the iterator straight away
+    #[test]
+    fn test_drain() {
+        let mut list: LinkedList<i32> = (0..5).collect();
+        let drained: Vec<i32> = list.drain().collect();
+        assert_eq!(drained, [0, 1, 2, 3, 4]);
+        assert!(list.is_empty());
+
+        list.extend(0..5);
+        for elem in list.drain() {
+            if elem == 1 {
+                break;
+            }
+        }
+        assert!(list.is_empty());
+        assert_consistent(&list);
+
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut list = counter_list(4, &drops);
+        let mut drain = list.drain();
+        assert_eq!(drain.len(), 4);
+        assert_eq!(drain.next().map(|counter| counter.value), Some(0));
+        drop(drain);
+        assert_eq!(drops.get(), 4);
+        drop(list.drain());
+        assert!(list.is_empty());
+
+        list.push_back(DropCounter { value: 9, drops: drops.clone() });
+        assert_eq!(list.len(), 1);
+        assert_consistent(&list);
+    }
+
+    // Test retaining the even numbers, with runs removed at the head, middle and tail
+    #[test]
+    fn test_retain() {
+        let mut list: LinkedList<i32> = (1..=10).collect();
+        let mut visited = 0;
+        list.retain(|x| {
+            visited += 1;
+            x % 2 == 0
+        });
+        assert_eq!(visited, 10);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [2, 4, 6, 8, 10]);
+        assert_eq!(list.len(), 5);
+        assert_consistent(&list);
+
+        let mut list: LinkedList<i32> = [1, 1, 5, 2, 7, 7, 3, 9, 9].into_iter().collect();
+        list.retain(|&x| x == 5 || x == 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 3]);
+        assert_consistent(&list);
+    }
+
+    // Test that retain can remove every element
+    #[test]
+    fn test_retain_none() {
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut list = counter_list(6, &drops);
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(drops.get(), 6);
+        assert_consistent(&list);
+
+        list.retain(|_| true);
+        assert!(list.is_empty());
+    }
+
+    // Test inclusive splitting against the str::split_inclusive examples
+    #[test]
+    fn test_into_split_inclusive_by() {
+        let cases: [(&str, &[&str]); 5] = [
+            ("Mary had a little lamb\nlittle lamb\nlittle lamb.\n", &["Mary had a little lamb\n", "little lamb\n", "little lamb.\n"]),
+            ("\nA\nB\nC\n\nD", &["\n", "A\n", "B\n", "C\n", "\n", "D"]),
+            ("", &[]),
+            ("abc", &["abc"]),
+            ("\n\n", &["\n", "\n"]),
+        ];
+
+        for (input, expected) in cases {
+            let list: LinkedList<char> = input.chars().collect();
+            let segments = list.into_split_inclusive_by(|&c| c == '\n');
+            let strings: Vec<String> = segments.iter().map(|segment| segment.iter().collect()).collect();
+            assert_eq!(strings, expected, "{:?}", input);
+            assert_eq!(strings, input.split_inclusive('\n').collect::<Vec<_>>());
+            for segment in &segments {
+                assert_consistent(segment);
+            }
+        }
+    }
+
+    // Test that flattening the inclusive segments gives back the original list
+    #[test]
+    fn test_into_split_inclusive_by_round_trip() {
+        let mut rng = crate::testing::XorShift::new(0x5e9);
+        for _ in 0..100 {
+            let elems: Vec<usize> = (0..rng.below(20)).map(|_| rng.below(4)).collect();
+            let list: LinkedList<usize> = elems.iter().copied().collect();
+            let segments = list.into_split_inclusive_by(|&x| x == 0);
+
+            let mut flat = LinkedList::new();
+            for segment in segments {
+                assert!(!segment.is_empty());
+                flat.extend_list(segment);
+            }
+            assert!(flat.iter().eq(elems.iter()));
+            assert_eq!(flat.len(), elems.len());
+        }
+    }
+
+    // Test retain_mut decrementing a TTL and dropping the entries that expire
+    #[test]
+    fn test_retain_mut() {
+        let mut list: LinkedList<(char, u32)> = [('a', 1), ('b', 3), ('c', 2), ('d', 1), ('e', 4)].into_iter().collect();
+        let mut visited = 0;
+        list.retain_mut(|(_, ttl)| {
+            visited += 1;
+            *ttl -= 1;
+            *ttl > 0
+        });
+        assert_eq!(visited, 5);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [('b', 2), ('c', 1), ('e', 3)]);
+        assert_eq!(list.len(), 3);
+
+        list.retain_mut(|(_, ttl)| {
+            *ttl -= 1;
+            *ttl > 0
+        });
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [('b', 1), ('e', 2)]);
+        assert_consistent(&list);
+    }
+
+    /// Token of the arithmetic expressions read by `tokenize`.
+    #[derive(Debug, PartialEq)]
+    enum Token {
+        Num(u32),
+        Op(char),
+        Pow,
+    }
+
+    /// Tokenizes an expression held in a list of characters, using only the cursor.
+    fn tokenize(list: &mut LinkedList<char>) -> Vec<Token> {
+        let mut cursor = list.cursor_front_mut();
+        let mut tokens = Vec::new();
+        loop {
+            match cursor.peek_two() {
+                (None, _) => break,
+                (Some(c), _) if c.is_whitespace() => {
+                    cursor.consume_if(|_| true);
+                }
+                (Some(c), _) if c.is_ascii_digit() => {
+                    let mut value = 0;
+                    while let Some(digit) = cursor.consume_if(char::is_ascii_digit) {
+                        value = value * 10 + digit.to_digit(10).unwrap();
+                    }
+                    tokens.push(Token::Num(value));
+                }
+                (Some('*'), Some('*')) => {
+                    cursor.consume_if(|_| true);
+                    cursor.consume_if(|_| true);
+                    tokens.push(Token::Pow);
+                }
+                (Some(&c), _) => {
+                    cursor.consume_if(|_| true);
+                    tokens.push(Token::Op(c));
+                }
+            }
+        }
+        tokens
+    }
+
+    // Test a tokenizer built on two-element lookahead and conditional consumption
+    #[test]
+    fn test_cursor_tokenizer() {
+        let mut list: LinkedList<char> = "12 + 3*(40 - 5) ** 2".chars().collect();
+        let tokens = tokenize(&mut list);
+        assert_eq!(
+            tokens,
+            [
+                Token::Num(12),
+                Token::Op('+'),
+                Token::Num(3),
+                Token::Op('*'),
+                Token::Op('('),
+                Token::Num(40),
+                Token::Op('-'),
+                Token::Num(5),
+                Token::Op(')'),
+                Token::Pow,
+                Token::Num(2),
+            ]
+        );
+        assert!(list.is_empty());
+        assert_consistent(&list);
+        assert!(tokenize(&mut LinkedList::new()).is_empty());
+    }
+
+    // Test the cursor's movement, lookahead and conditional removal
+    #[test]
+    fn test_cursor_peek_two_and_consume_if() {
+        let mut list: LinkedList<i32> = (1..=4).collect();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.peek_two(), (Some(&1), Some(&2)));
+        assert_eq!(cursor.consume_if(|&x| x % 2 == 0), None);
+        cursor.move_next();
+        assert_eq!((cursor.index(), cursor.consume_if(|&x| x % 2 == 0)), (1, Some(2)));
+        assert_eq!(cursor.peek_two(), (Some(&3), Some(&4)));
+        if let Some(x) = cursor.current() {
+            *x *= 10;
+        }
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!((cursor.index(), cursor.peek_two()), (3, (None, None)));
+        assert_eq!(cursor.consume_if(|_| true), None);
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 30, 4]);
+        assert_consistent(&list);
+    }
+
+    // Test clamped cursor seeking on empty lists and for in-range and far-out-of-range indices
+    #[test]
+    fn test_cursor_seek_to_clamped() {
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        cursor.seek_to_clamped(0);
+        cursor.seek_to_clamped(usize::MAX);
+        assert_eq!((cursor.index(), cursor.current()), (0, None));
+
+        let mut list: LinkedList<i32> = (0..5).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.seek_to_clamped(2);
+        assert_eq!((cursor.index(), cursor.peek_two()), (2, (Some(&2), Some(&3))));
+        cursor.seek_to_clamped(1);
+        assert_eq!(cursor.index(), 2);
+        cursor.seek_to_clamped(usize::MAX);
+        assert_eq!((cursor.index(), cursor.peek_two()), (4, (Some(&4), None)));
+        assert_eq!(cursor.consume_if(|_| true), Some(4));
+        cursor.seek_to_clamped(10);
+        assert_eq!((cursor.index(), cursor.current()), (4, None));
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_consistent(&list);
+    }
+
+    // Test the merge sort on random, sorted and reverse-sorted lists
+    #[test]
+    fn test_sort() {
+        let mut rng = crate::testing::XorShift::new(0x5027);
+        for len in [0, 1, 2, 3, 7, 8, 9, 100, 1000, 4097] {
+            let elems: Vec<u64> = (0..len).map(|_| rng.next_u64() % 1000).collect();
+            let mut expected = elems.clone();
+            expected.sort();
+
+            let mut list: LinkedList<u64> = elems.iter().copied().collect();
+            list.sort();
+            assert!(list.iter().eq(expected.iter()), "length {}", len);
+            assert_consistent(&list);
+
+            list.sort();
+            assert!(list.iter().eq(expected.iter()));
+            list.sort_by(|a, b| b.cmp(a));
+            assert!(list.iter().eq(expected.iter().rev()));
+            list.sort_by(|a, b| a.cmp(b));
+            assert!(list.iter().eq(expected.iter()));
+            assert_eq!(list.len(), len);
+        }
+
+        let mut large: LinkedList<u64> = (0..200_000).map(|_| rng.next_u64()).collect();
+        large.sort();
+        assert!(large.iter().zip(large.iter().skip(1)).all(|(a, b)| a <= b));
+        assert_consistent(&large);
+    }
+
+    // Test that sort_by_key keeps elements with equal keys in their original order
+    #[test]
+    fn test_sort_by_key_is_stable() {
+        let mut rng = crate::testing::XorShift::new(0x57ab);
+        let elems: Vec<(usize, usize)> = (0..500).map(|index| (rng.below(10), index)).collect();
+        let mut expected = elems.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        let mut list: LinkedList<(usize, usize)> = elems.into_iter().collect();
+        list.sort_by_key(|&(key, _)| key);
+        assert!(list.iter().eq(expected.iter()));
+        assert_consistent(&list);
+    }
+
+    // Test that a panicking comparator leaves the list consistent
+    #[test]
+    fn test_sort_by_unwind() {
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut list = counter_list(10, &drops);
+        let mut calls = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                calls += 1;
+                assert!(calls < 8, "comparator failed");
+                b.cmp(a)
+            })
+        }));
+        assert!(result.is_err());
+        assert_consistent(&list);
+        assert_eq!(list.len() + drops.get(), 10);
+    }
+
+    // Test that an owned iterator keeps the list alive after the original Rc is dropped
+    #[test]
+    fn test_iter_owned_outlives_rc() {
+        let list: Rc<LinkedList<String>> = Rc::new(["a", "b", "c"].iter().map(|s| s.to_string()).collect());
+        let weak = Rc::downgrade(&list);
+        let mut iter = list.iter_owned();
+        assert_eq!(Rc::strong_count(&list), 2);
+        drop(list);
+        assert_eq!(weak.strong_count(), 1);
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next().as_deref(), Some("a"));
+        let rest: Vec<String> = iter.by_ref().collect();
+        assert_eq!(rest, ["b", "c"]);
+        assert_eq!(iter.next(), None);
+        assert!(weak.upgrade().is_some());
+
+        drop(iter);
+        assert!(weak.upgrade().is_none());
+    }
+
+    // Test that an owned iterator can be stored as a boxed 'static iterator
+    #[test]
+    fn test_iter_owned_static() {
+        fn boxed(list: &Rc<LinkedList<i32>>) -> Box<dyn Iterator<Item = i32> + 'static> {
+            Box::new(list.iter_owned())
+        }
+
+        let list = Rc::new((1..=4).collect::<LinkedList<i32>>());
+        let iter = boxed(&list);
+        let copy = list.iter_owned().skip(2);
+        assert_eq!(Rc::strong_count(&list), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(copy.clone().collect::<Vec<_>>(), [3, 4]);
+        drop(copy);
+        assert_eq!(Rc::strong_count(&list), 1);
+
+        let empty = Rc::new(LinkedList::<i32>::new());
+        assert_eq!(empty.iter_owned().next(), None);
+    }
 
+    // Test that truncate keeps the prefix and drops the rest
+    #[test]
+    fn test_truncate() {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        list.truncate(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_consistent(&list);
+
+        list.push_back(9);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 9]);
+
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert_consistent(&list);
+    }
+
+    // Test that truncating to the length or more changes nothing
+    #[test]
+    fn test_truncate_noop() {
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        list.truncate(3);
+        list.truncate(10);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(list.len(), 3);
+
+        let mut empty = LinkedList::<i32>::new();
+        empty.truncate(0);
+        assert!(empty.is_empty());
+    }
+
+    // Test that truncate drops every cut element exactly once, even for long lists
+    #[test]
+    fn test_truncate_drops() {
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut list = counter_list(10, &drops);
+        list.truncate(4);
+        assert_eq!(drops.get(), 6);
+        drop(list);
+        assert_eq!(drops.get(), 10);
+
+        let mut long: LinkedList<u32> = (0..1_000_000).collect();
+        long.truncate(1);
+        assert_eq!(long.len(), 1);
+        assert_eq!(long.front(), Some(&0));
+    }
+
+    // Test that a vector survives a round trip through a list
+    #[test]
+    fn test_vec_round_trip() {
+        for vec in [vec![], vec![1], (1..=100).collect::<Vec<i32>>()] {
+            let list = LinkedList::from(vec.clone());
+            assert_eq!(list.len(), vec.len());
+            assert_consistent(&list);
+            let back: Vec<i32> = list.into();
+            assert_eq!(back, vec);
+        }
+    }
+
+    // Test the conversions from arrays and slices
+    #[test]
+    fn test_from_array_and_slice() {
+        let list = LinkedList::from([1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(Vec::from(list), [1, 2, 3]);
+
+        let empty = LinkedList::<i32>::from([]);
+        assert!(empty.is_empty());
+
+        let words = ["a".to_string(), "b".to_string()];
+        let list = LinkedList::from(&words[..]);
+        assert_consistent(&list);
+        assert_eq!(Vec::from(list), words);
+    }
+
+    // Test that the size hints of the iterators stay exact as they are consumed
+    #[test]
+    fn test_iter_exact_size() {
+        let mut list: LinkedList<i32> = (1..=4).collect();
         let mut iter = list.iter();
-        assert_eq!(iter.next(), Some(&1));
-        assert_eq!(iter.next(), Some(&2));
-        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        iter.next();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.remainder().len(), 3);
+        iter.nth(1);
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        iter.next();
+        assert_eq!(iter.len(), 0);
         assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(list.view_from(1).iter().len(), 3);
+
+        let mut iter_mut = list.iter_mut();
+        assert_eq!(iter_mut.len(), 4);
+        iter_mut.next();
+        assert_eq!(iter_mut.size_hint(), (3, Some(3)));
+
+        let mut into_iter = list.into_iter();
+        into_iter.next();
+        assert_eq!(into_iter.len(), 3);
+        assert_eq!(into_iter.zip(0..10).len(), 3);
     }
 
-    // Test the length of the list after operations.
+    // Test that a cloned iterator advances independently of the original
     #[test]
-    fn test_length_after_operations() {
-        let mut list = LinkedList::new();
+    fn test_iter_clone_independent() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        let mut iter = list.iter();
+        iter.next();
+        let mut ahead = iter.clone();
+        assert_eq!(ahead.by_ref().collect::<Vec<_>>(), [&2, &3]);
+        assert_eq!(ahead.len(), 0);
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(&2));
+    }
 
-        list.push(1);
-        list.append(2);
-        assert_eq!(list.len(), 2);
+    // Test the Debug output of the iterators
+    #[test]
+    fn test_iter_debug() {
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        let mut iter = list.iter();
+        iter.next();
+        assert_eq!(format!("{:?}", iter), "Iter[2, 3]");
+        let mut iter_mut = list.iter_mut();
+        iter_mut.next();
+        assert_eq!(format!("{:?}", iter_mut), "IterMut[2, 3]");
+        assert_eq!(format!("{:?}", list.into_iter()), "IntoIter[1, 2, 3]");
+    }
 
-        list.pop();
-        assert_eq!(list.len(), 1);
+    // Test that par_map matches a sequential map for several thread counts
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_par_map() {
+        let list: LinkedList<u64> = (1..=23).collect();
+        let expected: LinkedList<String> = list.iter().map(|n| (n * n).to_string()).collect();
+        for threads in [1, 2, 8, 64] {
+            let mapped = list.par_map(threads, |n| (n * n).to_string());
+            assert_eq!(mapped, expected, "threads = {}", threads);
+            assert_consistent(&mapped);
+        }
+
+        let empty = LinkedList::<u64>::new();
+        assert!(empty.par_map(4, |n| n + 1).is_empty());
+    }
+
+    // Test that par_map rejects a thread count of zero
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "par_map: threads must be non-zero")]
+    fn test_par_map_zero_threads() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        list.par_map(0, |n| n + 1);
+    }
+
+    // Test that a panic in the mapping closure reaches the caller
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "bad element")]
+    fn test_par_map_propagates_panic() {
+        let list: LinkedList<i32> = (1..=10).collect();
+        list.par_map(3, |&n| if n == 7 { panic!("bad element") } else { n });
+    }
+
+    // Test reading and writing elements through the index operators
+    #[test]
+    fn test_index() {
+        let mut list: LinkedList<i32> = (1..=4).collect();
+        assert_eq!(list[0], 1);
+        assert_eq!(list[3], 4);
+
+        list[2] = 30;
+        list[0] += 10;
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [11, 2, 30, 4]);
+        assert_consistent(&list);
+    }
+
+    // Test that indexing past the end panics with the index and length
+    #[test]
+    #[should_panic(expected = "index: index 3 out of range for list of length 3")]
+    fn test_index_out_of_range() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        let _ = list[3];
+    }
+
+    // Test that mutable indexing past the end panics with the index and length
+    #[test]
+    #[should_panic(expected = "index_mut: index 0 out of range for list of length 0")]
+    fn test_index_mut_out_of_range() {
+        let mut list = LinkedList::<i32>::new();
+        list[0] = 1;
     }
 }
@@ -0,0 +1,88 @@
+#[cfg(feature = "stats")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A snapshot of the operation counters of a list.
+///
+/// Only counted work done by the list itself is recorded:
+///
+/// * `allocations` - nodes allocated for new elements.
+/// * `frees` - nodes deallocated when elements are removed or the list is cleared.
+/// * `traversal_steps` - links followed while searching for a position, such as the
+///   tail of a singly list or the node at an index. Iterating with `iter()` is not counted.
+/// * `relinks` - nodes or whole chains moved to a new position without reallocation.
+#[cfg(feature = "stats")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListStats {
+    /// Nodes allocated for new elements.
+    pub allocations: usize,
+    /// Nodes deallocated.
+    pub frees: usize,
+    /// Links followed while searching for a position.
+    pub traversal_steps: usize,
+    /// Nodes or chains moved without reallocation.
+    pub relinks: usize,
+}
+
+/// The live counters embedded in every list.
+///
+/// Without the `stats` feature this is a zero-sized type and every method is an
+/// empty inline function, so the counters cost nothing.
+#[derive(Debug, Default)]
+pub(crate) struct Counters {
+    #[cfg(feature = "stats")]
+    allocations: AtomicUsize,
+    #[cfg(feature = "stats")]
+    frees: AtomicUsize,
+    #[cfg(feature = "stats")]
+    traversal_steps: AtomicUsize,
+    #[cfg(feature = "stats")]
+    relinks: AtomicUsize,
+}
+
+#[cfg_attr(not(feature = "stats"), allow(unused_variables))]
+impl Counters {
+    /// Records `count` node allocations.
+    #[inline]
+    pub(crate) fn allocated(&self, count: usize) {
+        #[cfg(feature = "stats")]
+        self.allocations.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Records `count` node deallocations.
+    #[inline]
+    pub(crate) fn freed(&self, count: usize) {
+        #[cfg(feature = "stats")]
+        self.frees.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Records `count` links followed during a search.
+    #[inline]
+    pub(crate) fn traversed(&self, count: usize) {
+        #[cfg(feature = "stats")]
+        self.traversal_steps.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Records `count` relinked nodes or chains.
+    #[inline]
+    pub(crate) fn relinked(&self, count: usize) {
+        #[cfg(feature = "stats")]
+        self.relinks.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Reads the current counter values.
+    #[cfg(feature = "stats")]
+    pub(crate) fn snapshot(&self) -> ListStats {
+        ListStats {
+            allocations: self.allocations.load(Ordering::Relaxed),
+            frees: self.frees.load(Ordering::Relaxed),
+            traversal_steps: self.traversal_steps.load(Ordering::Relaxed),
+            relinks: self.relinks.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Sets every counter back to zero.
+    #[cfg(feature = "stats")]
+    pub(crate) fn reset(&mut self) {
+        *self = Counters::default();
+    }
+}
@@ -0,0 +1,446 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::{doubly, single};
+
+/// Structured description of the first difference between two lists.
+///
+/// Produced by [`crate::single::LinkedList::diff`] and used by the
+/// [`assert_list_eq!`](crate::assert_list_eq) macro to render readable failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListDiff<'a, T> {
+    /// Index of the first position at which the lists differ, if any.
+    pub index: Option<usize>,
+    /// Element of the left list at `index`, or `None` if the left list ended there.
+    pub left: Option<&'a T>,
+    /// Element of the right list at `index`, or `None` if the right list ended there.
+    pub right: Option<&'a T>,
+    /// Number of elements in the left list.
+    pub left_len: usize,
+    /// Number of elements in the right list.
+    pub right_len: usize,
+}
+
+impl<'a, T: PartialEq> ListDiff<'a, T> {
+    /// Compares two sequences of elements and records where they first differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The elements of the left list, front to back.
+    /// * `right` - The elements of the right list, front to back.
+    pub fn between<L, R>(left: L, right: R) -> Self
+    where
+        L: IntoIterator<Item = &'a T>,
+        R: IntoIterator<Item = &'a T>,
+    {
+        let mut left = left.into_iter();
+        let mut right = right.into_iter();
+        let mut diff = ListDiff { index: None, left: None, right: None, left_len: 0, right_len: 0 };
+
+        loop {
+            let pair = (left.next(), right.next());
+            if diff.index.is_none() && pair.0 != pair.1 {
+                diff.index = Some(diff.left_len);
+                diff.left = pair.0;
+                diff.right = pair.1;
+            }
+
+            match pair {
+                (None, None) => return diff,
+                (l, r) => {
+                    diff.left_len += usize::from(l.is_some());
+                    diff.right_len += usize::from(r.is_some());
+                }
+            }
+        }
+    }
+}
+
+impl<T> ListDiff<'_, T> {
+    /// Returns `true` if the lists were equal.
+    pub fn is_empty(&self) -> bool {
+        self.index.is_none()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for ListDiff<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let index = match self.index {
+            None => return write!(f, "lists are equal ({} elements)", self.left_len),
+            Some(index) => index,
+        };
+
+        write!(f, "lists differ at index {}: ", index)?;
+        match self.left {
+            Some(elem) => write!(f, "left = {:?}", elem)?,
+            None => write!(f, "left has no element")?,
+        }
+        match self.right {
+            Some(elem) => write!(f, ", right = {:?}", elem)?,
+            None => write!(f, ", right has no element")?,
+        }
+        if self.left_len != self.right_len {
+            write!(f, " (left length {}, right length {})", self.left_len, self.right_len)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Asserts that two lists are equal, printing a concise [`ListDiff`] on failure.
+///
+/// Both arguments must provide a `diff` method, such as [`crate::single::LinkedList::diff`].
+#[macro_export]
+macro_rules! assert_list_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        let diff = $left.diff(&$right);
+        if !diff.is_empty() {
+            panic!("assertion `left == right` failed: {}", diff);
+        }
+    };
+}
+
+/// An operation of the fuzz harness, decoded from raw input bytes.
+///
+/// Index arguments are raw bytes; they are reduced modulo the list's length when
+/// the operation is applied, so every decoded sequence is valid for every list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListOp {
+    /// Pushes the value to the front.
+    PushFront(u8),
+    /// Pushes the value to the back.
+    PushBack(u8),
+    /// Pops the front element.
+    PopFront,
+    /// Pops the back element.
+    PopBack,
+    /// Inserts `value` at `index % (len + 1)`.
+    Insert {
+        /// The raw position.
+        index: u8,
+        /// The element to insert.
+        value: u8,
+    },
+    /// Removes the element at `index % len`, if the list is not empty.
+    Remove {
+        /// The raw position.
+        index: u8,
+    },
+    /// Splits the list: the singly list into `at % 4 + 1` parts that are appended
+    /// back together in order, the doubly list by keeping only its last
+    /// `at % (len + 1)` elements.
+    Split {
+        /// The raw split argument.
+        at: u8,
+    },
+    /// Appends a second list of `len % 8` elements counting up from `first`.
+    Append {
+        /// The raw length of the appended list.
+        len: u8,
+        /// The first appended element.
+        first: u8,
+    },
+    /// Checks the elements through the iterators and indexed access.
+    Iterate,
+    /// Turns ring mode of the doubly list on or off; the singly list ignores it.
+    ToggleRing,
+    /// Removes every element.
+    Clear,
+}
+
+impl ListOp {
+    /// Decodes `data` into a sequence of operations.
+    ///
+    /// Each operation takes one opcode byte followed by its arguments; arguments
+    /// missing at the end of the input read as zero.
+    pub fn decode(data: &[u8]) -> Vec<ListOp> {
+        let mut bytes = data.iter().copied();
+        let mut ops = Vec::new();
+        while let Some(opcode) = bytes.next() {
+            let mut arg = || bytes.next().unwrap_or(0);
+            ops.push(match opcode % 11 {
+                0 => ListOp::PushFront(arg()),
+                1 => ListOp::PushBack(arg()),
+                2 => ListOp::PopFront,
+                3 => ListOp::PopBack,
+                4 => ListOp::Insert { index: arg(), value: arg() },
+                5 => ListOp::Remove { index: arg() },
+                6 => ListOp::Split { at: arg() },
+                7 => ListOp::Append { len: arg(), first: arg() },
+                8 => ListOp::Iterate,
+                9 => ListOp::ToggleRing,
+                _ => ListOp::Clear,
+            });
+        }
+        ops
+    }
+
+    /// Returns the elements appended by [`ListOp::Append`].
+    fn appended(len: u8, first: u8) -> impl Iterator<Item = u8> {
+        (0..len % 8).map(move |i| first.wrapping_add(i))
+    }
+}
+
+/// Applies the operations decoded from `data` to a singly list and to a `Vec`
+/// oracle, checking that both hold the same elements after every step.
+///
+/// This is the body of the `singly` fuzz target.
+///
+/// # Panics
+///
+/// Panics, naming the step and the operation, if the list diverges from the
+/// oracle or fails [`single::LinkedList::assert_consistent`].
+pub fn run_singly_ops(data: &[u8]) {
+    let mut list = single::LinkedList::new();
+    let mut oracle: Vec<u8> = Vec::new();
+
+    for (step, op) in ListOp::decode(data).into_iter().enumerate() {
+        match op {
+            ListOp::PushFront(value) => {
+                list.push_front(value);
+                oracle.insert(0, value);
+            }
+            ListOp::PushBack(value) => {
+                list.push_back(value);
+                oracle.push(value);
+            }
+            ListOp::PopFront => {
+                let expected = (!oracle.is_empty()).then(|| oracle.remove(0));
+                assert_eq!(list.pop_front(), expected, "step {}: {:?}", step, op);
+            }
+            ListOp::PopBack => assert_eq!(list.pop_back(), oracle.pop(), "step {}: {:?}", step, op),
+            ListOp::Insert { index, value } => {
+                let index = usize::from(index) % (oracle.len() + 1);
+                match list.entry_at(index) {
+                    single::Entry::Occupied(entry) => {
+                        entry.insert_before(value);
+                    }
+                    single::Entry::Vacant(entry) => {
+                        entry.insert(value);
+                    }
+                }
+                oracle.insert(index, value);
+            }
+            ListOp::Remove { index } => {
+                if !oracle.is_empty() {
+                    let index = usize::from(index) % oracle.len();
+                    let removed = match list.entry_at(index) {
+                        single::Entry::Occupied(entry) => Some(entry.remove()),
+                        single::Entry::Vacant(_) => None,
+                    };
+                    assert_eq!(removed, Some(oracle.remove(index)), "step {}: {:?}", step, op);
+                }
+            }
+            ListOp::Split { at } => {
+                let parts = std::mem::take(&mut list).split_into(usize::from(at % 4) + 1);
+                let shortest = parts.iter().map(|part| part.len()).min().unwrap_or(0);
+                for part in parts {
+                    assert!(part.len() - shortest <= 1, "step {}: {:?} made uneven parts", step, op);
+                    list.extend_list(part);
+                }
+            }
+            ListOp::Append { len, first } => {
+                list.extend_list(ListOp::appended(len, first).collect());
+                oracle.extend(ListOp::appended(len, first));
+            }
+            ListOp::Iterate => {
+                assert!(list.iter().eq(oracle.iter()), "step {}: {:?}", step, op);
+                for (index, elem) in oracle.iter().enumerate() {
+                    assert_eq!(list.get(index), Some(elem), "step {}: {:?}", step, op);
+                }
+                assert_eq!(list.get(oracle.len()), None, "step {}: {:?}", step, op);
+            }
+            ListOp::ToggleRing => {}
+            ListOp::Clear => {
+                list.clear();
+                oracle.clear();
+            }
+        }
+
+        assert_eq!(list.len(), oracle.len(), "step {}: {:?}", step, op);
+        assert!(list.iter().eq(oracle.iter()), "step {}: {:?}", step, op);
+        assert_eq!(list.assert_consistent(), Ok(()), "step {}: {:?}", step, op);
+    }
+}
+
+/// Applies the operations decoded from `data` to both kinds of doubly list, with
+/// and without length tracking, each checked against a `VecDeque` oracle.
+///
+/// This is the body of the `doubly` fuzz target.
+///
+/// # Panics
+///
+/// Panics, naming the step and the operation, if a list diverges from the oracle
+/// or fails [`doubly::LinkedList::validate`].
+pub fn run_doubly_ops(data: &[u8]) {
+    let ops = ListOp::decode(data);
+    check_doubly_ops(doubly::LinkedList::new(), &ops);
+    check_doubly_ops(doubly::UncountedList::uncounted(), &ops);
+}
+
+/// Applies `ops` to `list` and to a `VecDeque` oracle; see [`run_doubly_ops`].
+fn check_doubly_ops<const TRACK_LEN: bool>(mut list: doubly::LinkedList<u8, TRACK_LEN>, ops: &[ListOp]) {
+    let mut oracle: VecDeque<u8> = VecDeque::new();
+
+    for (step, &op) in ops.iter().enumerate() {
+        match op {
+            ListOp::PushFront(value) => {
+                list.push_front(value);
+                oracle.push_front(value);
+            }
+            ListOp::PushBack(value) => {
+                list.push_back(value);
+                oracle.push_back(value);
+            }
+            ListOp::PopFront => assert_eq!(list.pop_front(), oracle.pop_front(), "step {}: {:?}", step, op),
+            ListOp::PopBack => assert_eq!(list.pop_back(), oracle.pop_back(), "step {}: {:?}", step, op),
+            ListOp::Insert { index, value } => {
+                let index = usize::from(index) % (oracle.len() + 1);
+                let mut single = doubly::LinkedList::default();
+                single.push_back(value);
+                list.splice_at(index, single);
+                oracle.insert(index, value);
+            }
+            ListOp::Remove { index } => {
+                if !oracle.is_empty() {
+                    let index = usize::from(index) % oracle.len();
+                    let mut position = 0;
+                    let mut removed = None;
+                    list.edit_spans(|editor| {
+                        if position == index {
+                            removed = editor.remove_current();
+                            editor.skip(usize::MAX);
+                        }
+                        position += 1;
+                    });
+                    assert_eq!(removed, oracle.remove(index), "step {}: {:?}", step, op);
+                }
+            }
+            ListOp::Split { at } => {
+                let keep = usize::from(at) % (oracle.len() + 1);
+                list.keep_last_n(keep);
+                oracle.drain(..oracle.len() - keep);
+            }
+            ListOp::Append { len, first } => {
+                let mut other = doubly::LinkedList::default();
+                for elem in ListOp::appended(len, first) {
+                    other.push_back(elem);
+                }
+                list.extend_list(other);
+                oracle.extend(ListOp::appended(len, first));
+            }
+            ListOp::Iterate => {
+                assert!(list.iter().eq(oracle.iter()), "step {}: {:?}", step, op);
+                assert!(list.iter().rev().eq(oracle.iter().rev()), "step {}: {:?}", step, op);
+                assert!(list.iter_with_neighbors().map(|(_, elem, _)| elem).eq(oracle.iter()), "step {}: {:?}", step, op);
+            }
+            ListOp::ToggleRing => {
+                if list.is_circular() {
+                    list.break_cycle();
+                } else {
+                    list.make_circular();
+                }
+            }
+            ListOp::Clear => {
+                list.clear();
+                oracle.clear();
+            }
+        }
+
+        assert_eq!(list.len(), oracle.len(), "step {}: {:?}", step, op);
+        assert!(list.iter().eq(oracle.iter()), "step {}: {:?}", step, op);
+        assert_eq!(list.validate(), Ok(()), "step {}: {:?}", step, op);
+    }
+}
+
+/// Small deterministic pseudo-random generator for model tests.
+#[cfg(test)]
+pub(crate) struct XorShift(u64);
+
+#[cfg(test)]
+impl XorShift {
+    /// Creates a generator from a non-zero seed.
+    pub(crate) fn new(seed: u64) -> Self {
+        XorShift(seed.max(1))
+    }
+
+    /// Returns the next pseudo-random value.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a pseudo-random value in `0..bound`.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_sequences() {
+        let diff = ListDiff::between(&[1, 2, 3], &[1, 2, 3]);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "lists are equal (3 elements)");
+    }
+
+    #[test]
+    fn test_content_mismatch() {
+        let diff = ListDiff::between(&[1, 2, 3], &[1, 5, 3]);
+        assert_eq!(diff.index, Some(1));
+        assert_eq!((diff.left, diff.right), (Some(&2), Some(&5)));
+        assert_eq!(diff.to_string(), "lists differ at index 1: left = 2, right = 5");
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let diff = ListDiff::between(&[1, 2], &[1, 2, 3, 4]);
+        assert_eq!(diff.index, Some(2));
+        assert_eq!((diff.left_len, diff.right_len), (2, 4));
+        assert_eq!(
+            diff.to_string(),
+            "lists differ at index 2: left has no element, right = 3 (left length 2, right length 4)"
+        );
+    }
+
+    /// Canned inputs for the fuzz harness, covering every opcode, short reads and
+    /// operations on empty lists.
+    const CORPUS: [&[u8]; 6] = [
+        &[],
+        &[2, 3, 5, 6, 8, 10],
+        &[0, 1, 1, 2, 0, 3, 4, 1, 9, 5, 0, 8, 6, 5, 8],
+        &[7, 7, 0, 9, 4, 3, 200, 5, 4, 6, 2, 8, 9, 3, 1, 255, 8],
+        &[1, 5, 1, 6, 1, 7, 9, 6, 1, 0, 4, 4, 255, 5, 9, 8, 10, 8],
+        &[4, 17, 42, 7],
+    ];
+
+    #[test]
+    fn test_decode_ops() {
+        let ops = ListOp::decode(&[0, 9, 15, 3, 4, 1]);
+        assert_eq!(ops, [ListOp::PushFront(9), ListOp::Insert { index: 3, value: 4 }, ListOp::PushBack(0)]);
+        assert_eq!(ListOp::decode(&[7]), [ListOp::Append { len: 0, first: 0 }]);
+    }
+
+    #[test]
+    fn test_replay_corpus() {
+        for data in CORPUS {
+            run_singly_ops(data);
+            run_doubly_ops(data);
+        }
+    }
+
+    #[test]
+    fn test_replay_random_inputs() {
+        let mut rng = XorShift::new(0xf022);
+        for _ in 0..50 {
+            let data: Vec<u8> = (0..rng.below(256)).map(|_| rng.next_u64() as u8).collect();
+            run_singly_ops(&data);
+            run_doubly_ops(&data);
+        }
+    }
+}
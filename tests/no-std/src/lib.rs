@@ -0,0 +1,24 @@
+//! Build check that the fixed-capacity list needs nothing beyond `core`.
+//!
+//! The main crate links `std`, so depending on it would prove nothing. This crate
+//! compiles the source of `linked_list::array` directly instead, with neither
+//! `std` nor `alloc` available.
+
+#![no_std]
+
+#[path = "../../../src/array.rs"]
+pub mod array;
+
+use array::ArrayLinkedList;
+
+/// Pushes `0, 1, 2, ...` until the list is full and returns the sum of its elements.
+pub fn fill_and_sum<const N: usize>(list: &mut ArrayLinkedList<u32, N>) -> u32 {
+    let mut next = 0;
+    while list.push_back(next).is_ok() {
+        next += 1;
+    }
+    list.iter().sum()
+}
+
+/// A list built in a static, where no allocator could be called.
+pub static EMPTY: ArrayLinkedList<u8, 8> = ArrayLinkedList::new();